@@ -1,23 +1,137 @@
 //! Distance constraint linking two simulation nodes.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::core::constants::*;
 
+/// Which side of the rest length a constraint resists.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum ConstraintKind {
+    /// Holds the nodes at exactly `rest_length`.
+    #[default]
+    Exact,
+    /// Only pulls the ends together once they are farther apart than `rest_length` (tethers, leashes);
+    /// shorter is free, down to `MIN_CONSTRAINT_DISTANCE`.
+    MaxOnly,
+    /// Only pushes the ends apart once they are closer together than `rest_length` (struts);
+    /// longer is free, up to `MAX_CONSTRAINT_DISTANCE`.
+    MinOnly,
+    /// Free to move anywhere between `min_length` and `max_length`.
+    Range,
+}
+
+impl ConstraintKind {
+    pub const ALL: [ConstraintKind; 4] = [
+        ConstraintKind::Exact,
+        ConstraintKind::MaxOnly,
+        ConstraintKind::MinOnly,
+        ConstraintKind::Range,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConstraintKind::Exact => "Exact",
+            ConstraintKind::MaxOnly => "Max Only",
+            ConstraintKind::MinOnly => "Min Only",
+            ConstraintKind::Range => "Range",
+        }
+    }
+}
+
+/// Sent when a breakable constraint is stretched past its limit and despawned.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct ConstraintBroken {
+    pub constraint: Entity,
+    pub node_a: Entity,
+    pub node_b: Entity,
+}
+
 /// A distance constraint entity linking two nodes.
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
 pub struct DistanceConstraint {
     pub node_a: Entity,
     pub node_b: Entity,
     pub rest_length: f32,
+    /// XPBD compliance (inverse stiffness). `0.0` is perfectly rigid; larger values stretch more.
+    pub compliance: f32,
+    pub kind: ConstraintKind,
+    /// Lower bound used by `ConstraintKind::Range`.
+    pub min_length: f32,
+    /// Upper bound used by `ConstraintKind::Range`.
+    pub max_length: f32,
+    /// Snaps once stretched past this multiple of its length; `None` never breaks.
+    pub break_ratio: Option<f32>,
 }
 
 impl DistanceConstraint {
     pub fn new(node_a: Entity, node_b: Entity, rest_length: f32) -> Self {
+        let rest_length = Self::clamp_rest_length(rest_length);
         Self {
             node_a,
             node_b,
-            rest_length: Self::clamp_rest_length(rest_length),
+            rest_length,
+            compliance: 0.0,
+            kind: ConstraintKind::Exact,
+            min_length: rest_length,
+            max_length: rest_length,
+            break_ratio: None,
+        }
+    }
+
+    pub fn with_compliance(mut self, compliance: f32) -> Self {
+        self.compliance = Self::clamp_compliance(compliance);
+        self
+    }
+
+    pub fn with_kind(mut self, kind: ConstraintKind, min_length: f32, max_length: f32) -> Self {
+        self.kind = kind;
+        self.set_range(min_length, max_length);
+        self
+    }
+
+    pub fn with_break_ratio(mut self, break_ratio: Option<f32>) -> Self {
+        self.break_ratio = break_ratio.map(Self::clamp_break_ratio);
+        self
+    }
+
+    /// Sets the `Range` bounds, clamped to the allowed distances and kept ordered.
+    pub fn set_range(&mut self, min_length: f32, max_length: f32) {
+        self.min_length = Self::clamp_rest_length(min_length);
+        self.max_length = Self::clamp_rest_length(max_length).max(self.min_length);
+    }
+
+    pub fn is_slack(&self) -> bool {
+        self.kind != ConstraintKind::Exact
+    }
+
+    /// Whether `distance` stretches this constraint past its break ratio.
+    pub fn is_overstretched(&self, distance: f32) -> bool {
+        let Some(ratio) = self.break_ratio else {
+            return false;
+        };
+        let length = match self.kind {
+            ConstraintKind::Range => self.max_length,
+            _ => self.rest_length,
+        };
+        distance > length * ratio
+    }
+
+    /// The length `distance` should be pulled back to, or `None` while it is within bounds.
+    pub fn target_length(&self, distance: f32) -> Option<f32> {
+        let (min, max) = match self.kind {
+            ConstraintKind::Exact => (self.rest_length, self.rest_length),
+            ConstraintKind::MaxOnly => (MIN_CONSTRAINT_DISTANCE, self.rest_length),
+            ConstraintKind::MinOnly => (self.rest_length, MAX_CONSTRAINT_DISTANCE),
+            ConstraintKind::Range => (self.min_length, self.max_length),
+        };
+
+        if distance < min {
+            Some(min)
+        } else if distance > max {
+            Some(max)
+        } else {
+            None
         }
     }
 
@@ -35,7 +149,68 @@ impl DistanceConstraint {
         }
     }
 
-    fn clamp_rest_length(length: f32) -> f32 {
+    pub fn clamp_rest_length(length: f32) -> f32 {
         length.clamp(MIN_CONSTRAINT_DISTANCE, MAX_CONSTRAINT_DISTANCE)
     }
+
+    pub fn clamp_break_ratio(ratio: f32) -> f32 {
+        ratio.clamp(MIN_BREAK_RATIO, MAX_BREAK_RATIO)
+    }
+
+    pub fn clamp_compliance(compliance: f32) -> f32 {
+        compliance.clamp(0.0, MAX_CONSTRAINT_COMPLIANCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constraint(kind: ConstraintKind) -> DistanceConstraint {
+        DistanceConstraint::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER, 50.0).with_kind(kind, 30.0, 80.0)
+    }
+
+    #[test]
+    fn max_only_pulls_in_past_rest_length_and_leaves_shorter_alone() {
+        let c = constraint(ConstraintKind::MaxOnly);
+        assert_eq!(c.target_length(70.0), Some(50.0));
+        assert_eq!(c.target_length(50.0), None);
+        assert_eq!(c.target_length(20.0), None);
+        assert_eq!(c.target_length(1.0), Some(MIN_CONSTRAINT_DISTANCE));
+    }
+
+    #[test]
+    fn min_only_pushes_out_below_rest_length_and_leaves_longer_alone() {
+        let c = constraint(ConstraintKind::MinOnly);
+        assert_eq!(c.target_length(30.0), Some(50.0));
+        assert_eq!(c.target_length(50.0), None);
+        assert_eq!(c.target_length(150.0), None);
+        assert_eq!(c.target_length(500.0), Some(MAX_CONSTRAINT_DISTANCE));
+    }
+
+    #[test]
+    fn range_is_free_between_its_bounds() {
+        let c = constraint(ConstraintKind::Range);
+        assert_eq!(c.target_length(20.0), Some(30.0));
+        for distance in [30.0, 50.0, 80.0] {
+            assert_eq!(c.target_length(distance), None);
+        }
+        assert_eq!(c.target_length(90.0), Some(80.0));
+    }
+
+    #[test]
+    fn exact_returns_to_rest_length_from_either_side() {
+        let c = constraint(ConstraintKind::Exact);
+        assert_eq!(c.target_length(40.0), Some(50.0));
+        assert_eq!(c.target_length(50.0), None);
+        assert_eq!(c.target_length(60.0), Some(50.0));
+    }
+
+    #[test]
+    fn range_bounds_stay_ordered_and_clamped() {
+        let c = DistanceConstraint::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER, 50.0).with_kind(ConstraintKind::Range, 90.0, 1000.0);
+        assert_eq!((c.min_length, c.max_length), (90.0, MAX_CONSTRAINT_DISTANCE));
+        let c = c.with_kind(ConstraintKind::Range, 120.0, 60.0);
+        assert_eq!((c.min_length, c.max_length), (120.0, 120.0));
+    }
 }
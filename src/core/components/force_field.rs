@@ -0,0 +1,145 @@
+//! Placeable force fields that push nodes around.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::constants::*;
+use crate::core::utils::value_noise;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum ForceFieldKind {
+    /// Blows along `direction`, with optional gusts.
+    #[default]
+    Wind,
+    /// Pulls toward the center; a negative strength pushes away instead.
+    Radial,
+    /// Swirls around the center, counter-clockwise for a positive strength.
+    Vortex,
+    /// Coherent noise that drifts over time.
+    Turbulence,
+}
+
+impl ForceFieldKind {
+    pub const ALL: [ForceFieldKind; 4] = [
+        ForceFieldKind::Wind,
+        ForceFieldKind::Radial,
+        ForceFieldKind::Vortex,
+        ForceFieldKind::Turbulence,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ForceFieldKind::Wind => "Wind",
+            ForceFieldKind::Radial => "Attractor / Repeller",
+            ForceFieldKind::Vortex => "Vortex",
+            ForceFieldKind::Turbulence => "Turbulence",
+        }
+    }
+}
+
+/// How a field's strength fades between its center and its radius.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum FieldFalloff {
+    /// Full strength everywhere inside the radius.
+    Constant,
+    #[default]
+    Linear,
+    /// Smoothstep, gentle at both the center and the edge.
+    Smooth,
+}
+
+impl FieldFalloff {
+    pub const ALL: [FieldFalloff; 3] = [FieldFalloff::Constant, FieldFalloff::Linear, FieldFalloff::Smooth];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FieldFalloff::Constant => "Constant",
+            FieldFalloff::Linear => "Linear",
+            FieldFalloff::Smooth => "Smooth",
+        }
+    }
+}
+
+/// A circular region that adds acceleration to every normal node inside it.
+#[derive(Component, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForceField {
+    pub kind: ForceFieldKind,
+    pub position: Vec2,
+    pub radius: f32,
+    pub falloff: FieldFalloff,
+    /// Acceleration at full strength.
+    pub strength: f32,
+    /// Wind heading, in radians.
+    pub direction: f32,
+    /// How much gusts vary the wind, as a fraction of `strength`.
+    pub gust: f32,
+    /// How quickly gusts and turbulence change, in cycles per second.
+    pub frequency: f32,
+    /// World units per turbulence cell; larger is smoother.
+    pub noise_scale: f32,
+}
+
+impl Default for ForceField {
+    fn default() -> Self {
+        Self {
+            kind: ForceFieldKind::Wind,
+            position: Vec2::ZERO,
+            radius: DEFAULT_FIELD_RADIUS,
+            falloff: FieldFalloff::Linear,
+            strength: DEFAULT_FIELD_STRENGTH,
+            direction: 0.0,
+            gust: 0.0,
+            frequency: 0.5,
+            noise_scale: 100.0,
+        }
+    }
+}
+
+impl ForceField {
+    pub fn new(kind: ForceFieldKind, position: Vec2) -> Self {
+        Self {
+            kind,
+            position,
+            ..default()
+        }
+    }
+
+    /// Strength multiplier in `[0, 1]` at `distance` from the center.
+    pub fn falloff_weight(&self, distance: f32) -> f32 {
+        if distance > self.radius || self.radius <= 0.0 {
+            return 0.0;
+        }
+
+        let t = 1.0 - distance / self.radius;
+        match self.falloff {
+            FieldFalloff::Constant => 1.0,
+            FieldFalloff::Linear => t,
+            FieldFalloff::Smooth => t * t * (3.0 - 2.0 * t),
+        }
+    }
+
+    /// Acceleration this field applies at `point` after `time` seconds of simulation.
+    pub fn acceleration_at(&self, point: Vec2, time: f32, seed: u64) -> Vec2 {
+        let offset = point - self.position;
+        let weight = self.falloff_weight(offset.length());
+        if weight == 0.0 {
+            return Vec2::ZERO;
+        }
+
+        let direction = match self.kind {
+            ForceFieldKind::Wind => {
+                let gust = value_noise(Vec2::new(time * self.frequency, 0.0), seed);
+                Vec2::from_angle(self.direction) * (1.0 + self.gust * gust)
+            }
+            ForceFieldKind::Radial => -offset.normalize_or_zero(),
+            ForceFieldKind::Vortex => offset.normalize_or_zero().perp(),
+            ForceFieldKind::Turbulence => {
+                let sample = point / self.noise_scale.max(1.0) + Vec2::splat(time * self.frequency);
+                Vec2::new(value_noise(sample, seed), value_noise(sample, seed ^ TURBULENCE_SEED_SALT))
+            }
+        };
+
+        direction * self.strength * weight
+    }
+}
@@ -3,19 +3,127 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Component, Clone, Debug, Default, Reflect, Serialize, Deserialize)]
+use crate::core::constants::{DEFAULT_JOINT_BEND_LIMIT, DEFAULT_LEAD_FACTOR, DEFAULT_RELEASE_RATIO};
+
+/// How the limbs of a set take turns stepping.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum GaitPattern {
+    /// Every limb steps whenever it likes.
+    #[default]
+    Free,
+    /// Limbs split into phase groups that never lift at the same time.
+    Alternating,
+}
+
+impl GaitPattern {
+    pub const ALL: [GaitPattern; 2] = [GaitPattern::Free, GaitPattern::Alternating];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GaitPattern::Free => "Free",
+            GaitPattern::Alternating => "Alternating",
+        }
+    }
+}
+
+/// Inverse kinematics solver a limb reaches for its target with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum IkSolver {
+    /// Forward And Backward Reaching IK; any chain length.
+    #[default]
+    Fabrik,
+    /// Closed-form law of cosines for two-segment limbs; longer chains fall back to FABRIK.
+    TwoBone,
+    /// Cyclic Coordinate Descent, rotating one joint at a time toward the target.
+    Ccd,
+}
+
+impl IkSolver {
+    pub const ALL: [IkSolver; 3] = [IkSolver::Fabrik, IkSolver::TwoBone, IkSolver::Ccd];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            IkSolver::Fabrik => "FABRIK",
+            IkSolver::TwoBone => "Two-Bone",
+            IkSolver::Ccd => "CCD",
+        }
+    }
+}
+
+/// Allowed bend at a joint, in radians, measured from the incoming segment to the outgoing one.
+///
+/// Positive angles turn counter-clockwise; `min..=max` may sit entirely on one side to keep a knee from hyperextending.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct JointLimit {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Default for JointLimit {
+    fn default() -> Self {
+        Self {
+            min: -DEFAULT_JOINT_BEND_LIMIT,
+            max: DEFAULT_JOINT_BEND_LIMIT,
+        }
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
 pub struct LimbSet {
     pub limbs: Vec<Limb>,
+    pub gait: GaitPattern,
+    /// Beats per second; each beat hands the turn to the next phase group.
+    pub cadence: Option<f32>,
 }
 
-#[derive(Clone, Debug, Reflect, Serialize, Deserialize)]
+impl LimbSet {
+    pub fn new(limbs: Vec<Limb>) -> Self {
+        Self { limbs, ..default() }
+    }
+
+    pub fn phase_count(&self) -> usize {
+        self.limbs.iter().map(|l| l.phase + 1).max().unwrap_or(1)
+    }
+
+    /// Splits limbs into two alternating phases: diagonal pairs on four limbs, tripods on six.
+    ///
+    /// Limbs are sorted onto a side by their target direction, then alternate down each
+    /// side, with the right side starting on the opposite phase of the left.
+    pub fn assign_alternating_phases(&mut self) {
+        let (mut left, mut right) = (0, 1);
+        for limb in self.limbs.iter_mut() {
+            let rank = if limb.target_direction_offset >= 0.0 { &mut left } else { &mut right };
+            limb.phase = *rank % 2;
+            *rank += 1;
+        }
+    }
+
+    /// Whether the limb at `index` may lift off this tick under the set's gait.
+    ///
+    /// `beat` is the current cadence beat, if the set keeps one.
+    pub fn may_start_step(&self, index: usize, beat: Option<u64>) -> bool {
+        if self.gait == GaitPattern::Free {
+            return true;
+        }
+
+        let phase = self.limbs[index].phase;
+        let partner_airborne = self.limbs.iter().any(|l| l.is_stepping && l.phase != phase);
+        let on_beat = beat.is_none_or(|beat| beat % self.phase_count() as u64 == phase as u64);
+        !partner_airborne && on_beat
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
 pub struct Limb {
     pub joints: Vec<Entity>,
     pub target: Vec2,
     pub lengths: Vec<f32>,
+    pub solver: IkSolver,
     pub iterations: usize,
     pub tolerance: f32,
     pub flip_bend: Vec<bool>,
+    /// Bend range at each joint; the foot has no outgoing segment, so its entry is unused.
+    pub joint_limits: Vec<JointLimit>,
     pub target_node: Option<Entity>,
     pub max_reach: f32,
     pub target_direction_offset: f32,
@@ -26,6 +134,33 @@ pub struct Limb {
     pub step_start: Vec2,
     pub step_dest: Vec2,
     pub step_progress: f32,
+    /// How far ahead of a moving body the foot is placed, as a multiple of the distance the
+    /// body covers during one step. `0.0` plants feet where the body is now.
+    pub lead_factor: f32,
+    /// How far a planted foot may be left behind, as a multiple of the limb's full length,
+    /// before it lets go and steps. Up to this, the segments stretch to keep the foot in place.
+    pub release_ratio: f32,
+    /// Phase group under an alternating gait; limbs in other groups wait while this one is lifted.
+    pub phase: usize,
+}
+
+impl Limb {
+    /// Bend range at joint `index`, falling back to the default for limbs saved without one.
+    pub fn joint_limit(&self, index: usize) -> JointLimit {
+        self.joint_limits.get(index).copied().unwrap_or_default()
+    }
+
+    /// Whether the foot is down on its contact point, `target`, rather than mid-step.
+    pub fn is_planted(&self) -> bool {
+        !self.is_stepping
+    }
+
+    /// Moves every world-space target the limb is tracking.
+    pub fn translate(&mut self, offset: Vec2) {
+        self.target += offset;
+        self.step_start += offset;
+        self.step_dest += offset;
+    }
 }
 
 impl Default for Limb {
@@ -34,9 +169,11 @@ impl Default for Limb {
             joints: Vec::new(),
             target: Vec2::ZERO,
             lengths: Vec::new(),
+            solver: IkSolver::Fabrik,
             iterations: 10,
             tolerance: 0.1,
             flip_bend: Vec::new(),
+            joint_limits: Vec::new(),
             target_node: None,
             max_reach: 100.0,
             target_direction_offset: 0.0,
@@ -47,6 +184,9 @@ impl Default for Limb {
             step_start: Vec2::ZERO,
             step_dest: Vec2::ZERO,
             step_progress: 0.0,
+            lead_factor: DEFAULT_LEAD_FACTOR,
+            release_ratio: DEFAULT_RELEASE_RATIO,
+            phase: 0,
         }
     }
 }
@@ -1,11 +1,17 @@
 //! Core simulation data types (components and resources).
 
 pub mod distance_constraint;
+pub mod force_field;
 pub mod limb;
 pub mod node;
+pub mod obstacle;
+pub mod pin;
 pub mod playground;
 
-pub use distance_constraint::DistanceConstraint;
-pub use limb::{Limb, LimbSet};
+pub use distance_constraint::{ConstraintBroken, ConstraintKind, DistanceConstraint};
+pub use force_field::{FieldFalloff, ForceField, ForceFieldKind};
+pub use limb::{GaitPattern, IkSolver, JointLimit, Limb, LimbSet};
 pub use node::{AnchorMovementMode, Node, NodeType, ProceduralPathType};
-pub use playground::{CellEntry, Playground};
+pub use obstacle::{Obstacle, ObstacleShape, ObstacleShapeKind};
+pub use pin::Pin;
+pub use playground::{BoundaryMode, CellEntry, Playground, PlaygroundShape};
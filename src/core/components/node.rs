@@ -48,7 +48,7 @@ pub enum ProceduralPathType {
     Wander,
 }
 
-#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[derive(Component, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
 #[serde(default)]
 #[require(Transform)]
 pub struct Node {
@@ -71,6 +71,19 @@ pub struct Node {
     pub target_position: Vec2,
     pub collision_damping: f32,
     pub constant_acceleration: Vec2,
+    /// Explicit mass; `None` derives it from the radius.
+    pub mass: Option<f32>,
+    /// Forces accumulated this tick, scaled by inverse mass and cleared in `verlet_step`.
+    #[serde(skip)]
+    pub force: Vec2,
+    /// Position at the start of the latest simulation tick, used to interpolate rendering.
+    #[serde(skip)]
+    #[reflect(ignore)]
+    pub tick_start_position: Option<Vec2>,
+    /// Row of the wander drift noise, drawn from `SimulationRng` the first time the node wanders.
+    #[serde(skip)]
+    #[reflect(ignore)]
+    pub wander_noise_row: Option<f32>,
 }
 
 impl Default for Node {
@@ -95,6 +108,10 @@ impl Default for Node {
             target_position: Vec2::ZERO,
             collision_damping: 0.5,
             constant_acceleration: Vec2::ZERO,
+            mass: None,
+            force: Vec2::ZERO,
+            tick_start_position: None,
+            wander_noise_row: None,
         }
     }
 }
@@ -123,13 +140,65 @@ impl Node {
         self
     }
 
+    pub fn with_mass(mut self, mass: f32) -> Self {
+        self.mass = Some(mass.clamp(MIN_NODE_MASS, MAX_NODE_MASS));
+        self
+    }
+
+    /// The explicit mass if set, otherwise one proportional to the node's area.
+    pub fn effective_mass(&self) -> f32 {
+        self.mass
+            .unwrap_or(self.radius * self.radius * MASS_PER_AREA)
+            .clamp(MIN_NODE_MASS, MAX_NODE_MASS)
+    }
+
+    pub fn inverse_mass(&self) -> f32 {
+        1.0 / self.effective_mass()
+    }
+
+    /// Adds a force for the next integration step; heavier nodes accelerate less.
+    pub fn apply_force(&mut self, force: Vec2) {
+        self.force += force;
+    }
+
+    /// Moves the node along with its steering targets, keeping its velocity.
+    pub fn translate(&mut self, offset: Vec2) {
+        self.position += offset;
+        self.prev_position += offset;
+        self.target_position += offset;
+        self.path_center += offset;
+        if let Some(start) = self.tick_start_position.as_mut() {
+            *start += offset;
+        }
+    }
+
+    /// Distance moved so far this tick.
+    ///
+    /// Unlike `position - prev_position`, this also holds for anchors and limbs, whose
+    /// `prev_position` is reset every tick.
+    pub fn tick_velocity(&self) -> Vec2 {
+        match self.tick_start_position {
+            Some(start) => self.position - start,
+            None => self.position - self.prev_position,
+        }
+    }
+
+    /// Blends from the tick-start position toward the simulated position by `alpha` in `[0, 1]`.
+    pub fn interpolated_position(&self, alpha: f32) -> Vec2 {
+        match self.tick_start_position {
+            Some(start) => start.lerp(self.position, alpha),
+            None => self.position,
+        }
+    }
+
     pub fn verlet_step(&mut self, dt: f32) {
         let velocity = self.position - self.prev_position;
-        let total_acceleration = self.acceleration + self.constant_acceleration;
+        let total_acceleration = self.acceleration + self.constant_acceleration + self.force * self.inverse_mass();
         let new_position = self.position + velocity * AIR_DAMPING + total_acceleration * dt * dt;
 
         self.prev_position = self.position;
         self.position = new_position;
         self.acceleration = Vec2::ZERO;
+        self.force = Vec2::ZERO;
     }
 }
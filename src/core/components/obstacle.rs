@@ -0,0 +1,234 @@
+//! Static obstacles that nodes collide with and creatures steer around.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::utils::convex_hull;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum ObstacleShapeKind {
+    #[default]
+    Circle,
+    Box,
+    Polygon,
+}
+
+impl ObstacleShapeKind {
+    pub const ALL: [ObstacleShapeKind; 3] = [ObstacleShapeKind::Circle, ObstacleShapeKind::Box, ObstacleShapeKind::Polygon];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ObstacleShapeKind::Circle => "Circle",
+            ObstacleShapeKind::Box => "Box",
+            ObstacleShapeKind::Polygon => "Polygon",
+        }
+    }
+}
+
+/// Outline of an obstacle, in local space around its position.
+#[derive(Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub enum ObstacleShape {
+    Circle { radius: f32 },
+    Box { half_size: Vec2 },
+    /// Convex, counter-clockwise vertices.
+    Polygon { points: Vec<Vec2> },
+}
+
+/// A solid, immovable shape inside the playground.
+#[derive(Component, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct Obstacle {
+    pub position: Vec2,
+    /// Rotation of the shape around `position`, in radians.
+    #[serde(default)]
+    pub rotation: f32,
+    pub shape: ObstacleShape,
+}
+
+impl Obstacle {
+    pub fn circle(position: Vec2, radius: f32) -> Self {
+        Self {
+            position,
+            rotation: 0.0,
+            shape: ObstacleShape::Circle { radius },
+        }
+    }
+
+    pub fn rect(position: Vec2, half_size: Vec2) -> Self {
+        Self {
+            position,
+            rotation: 0.0,
+            shape: ObstacleShape::Box { half_size },
+        }
+    }
+
+    /// Wraps world-space `points` in their convex hull, or `None` if they enclose no area.
+    pub fn polygon(points: &[Vec2]) -> Option<Self> {
+        let hull = convex_hull(points);
+        if hull.len() < 3 {
+            return None;
+        }
+
+        let position = hull.iter().sum::<Vec2>() / hull.len() as f32;
+        Some(Self {
+            position,
+            rotation: 0.0,
+            shape: ObstacleShape::Polygon {
+                points: hull.into_iter().map(|p| p - position).collect(),
+            },
+        })
+    }
+
+    pub fn kind(&self) -> ObstacleShapeKind {
+        match self.shape {
+            ObstacleShape::Circle { .. } => ObstacleShapeKind::Circle,
+            ObstacleShape::Box { .. } => ObstacleShapeKind::Box,
+            ObstacleShape::Polygon { .. } => ObstacleShapeKind::Polygon,
+        }
+    }
+
+    /// Distance from `point` to the surface, negative inside, and the outward normal there.
+    pub fn signed_distance(&self, point: Vec2) -> (f32, Vec2) {
+        let local = self.to_local(point);
+        let (distance, normal) = match &self.shape {
+            ObstacleShape::Circle { radius } => {
+                let length = local.length();
+                let normal = if length > f32::EPSILON { local / length } else { Vec2::X };
+                (length - radius, normal)
+            }
+            ObstacleShape::Box { half_size } => convex_signed_distance(&box_corners(*half_size), local),
+            ObstacleShape::Polygon { points } => convex_signed_distance(points, local),
+        };
+        (distance, Vec2::from_angle(self.rotation).rotate(normal))
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.signed_distance(point).0 <= 0.0
+    }
+
+    /// Fraction along `start..end` where the segment first enters the obstacle.
+    ///
+    /// Returns `Some(0.0)` when `start` is already inside.
+    pub fn ray_cast(&self, start: Vec2, end: Vec2) -> Option<f32> {
+        let start = self.to_local(start);
+        let end = self.to_local(end);
+        match &self.shape {
+            ObstacleShape::Circle { radius } => ray_cast_circle(start, end, *radius),
+            ObstacleShape::Box { half_size } => ray_cast_convex(&box_corners(*half_size), start, end),
+            ObstacleShape::Polygon { points } => ray_cast_convex(points, start, end),
+        }
+    }
+
+    /// World-space outline, with circles split into `circle_segments` edges.
+    pub fn outline(&self, circle_segments: usize) -> Vec<Vec2> {
+        let local = match &self.shape {
+            ObstacleShape::Circle { radius } => (0..circle_segments)
+                .map(|i| Vec2::from_angle(i as f32 / circle_segments as f32 * std::f32::consts::TAU) * *radius)
+                .collect(),
+            ObstacleShape::Box { half_size } => box_corners(*half_size).to_vec(),
+            ObstacleShape::Polygon { points } => points.clone(),
+        };
+        let rotation = Vec2::from_angle(self.rotation);
+        local.into_iter().map(|p| self.position + rotation.rotate(p)).collect()
+    }
+
+    fn to_local(&self, point: Vec2) -> Vec2 {
+        Vec2::from_angle(-self.rotation).rotate(point - self.position)
+    }
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+fn box_corners(half_size: Vec2) -> [Vec2; 4] {
+    [
+        Vec2::new(-half_size.x, -half_size.y),
+        Vec2::new(half_size.x, -half_size.y),
+        Vec2::new(half_size.x, half_size.y),
+        Vec2::new(-half_size.x, half_size.y),
+    ]
+}
+
+/// Outward normal of the edge `a -> b` on a counter-clockwise polygon.
+fn edge_normal(a: Vec2, b: Vec2) -> Vec2 {
+    let edge = b - a;
+    Vec2::new(edge.y, -edge.x).normalize_or_zero()
+}
+
+fn convex_signed_distance(points: &[Vec2], point: Vec2) -> (f32, Vec2) {
+    let mut deepest = (f32::MIN, Vec2::X);
+    let mut nearest = (f32::MAX, Vec2::ZERO);
+
+    for (i, &a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        let normal = edge_normal(a, b);
+        let plane_distance = (point - a).dot(normal);
+        if plane_distance > deepest.0 {
+            deepest = (plane_distance, normal);
+        }
+
+        let edge = b - a;
+        let t = ((point - a).dot(edge) / edge.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+        let closest = a + edge * t;
+        let distance = point.distance(closest);
+        if distance < nearest.0 {
+            nearest = (distance, closest);
+        }
+    }
+
+    if deepest.0 <= 0.0 {
+        deepest
+    } else {
+        (nearest.0, (point - nearest.1).normalize_or(deepest.1))
+    }
+}
+
+/// Cyrus-Beck clipping of `start..end` against a convex polygon.
+fn ray_cast_convex(points: &[Vec2], start: Vec2, end: Vec2) -> Option<f32> {
+    let dir = end - start;
+    let mut t_enter = 0.0_f32;
+    let mut t_exit = 1.0_f32;
+
+    for (i, &a) in points.iter().enumerate() {
+        let normal = edge_normal(a, points[(i + 1) % points.len()]);
+        let distance = (start - a).dot(normal);
+        let approach = dir.dot(normal);
+
+        if approach == 0.0 {
+            if distance > 0.0 {
+                return None;
+            }
+            continue;
+        }
+
+        let t = -distance / approach;
+        if approach < 0.0 {
+            t_enter = t_enter.max(t);
+        } else {
+            t_exit = t_exit.min(t);
+        }
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+
+    Some(t_enter)
+}
+
+fn ray_cast_circle(start: Vec2, end: Vec2, radius: f32) -> Option<f32> {
+    let c = start.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+
+    let dir = end - start;
+    let a = dir.length_squared();
+    let b = start.dot(dir);
+    let discriminant = b * b - a * c;
+    if a == 0.0 || discriminant < 0.0 {
+        return None;
+    }
+
+    let t = (-b - discriminant.sqrt()) / a;
+    (0.0..=1.0).contains(&t).then_some(t)
+}
@@ -0,0 +1,35 @@
+//! World pin attaching a single node to a fixed point.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::constants::*;
+
+/// Holds the node it is attached to at a world-space point.
+///
+/// Unlike an `Anchor`, a pinned node keeps its own type and solver weight; the pin only
+/// pulls it back toward `point`. On a limb's foot joint the pin pulls the limb target instead,
+/// and on its other joints it is applied after the IK solve.
+#[derive(Component, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct Pin {
+    pub point: Vec2,
+    /// XPBD compliance, as on `DistanceConstraint`. `0.0` holds the node exactly on the point.
+    pub compliance: f32,
+}
+
+impl Pin {
+    pub fn new(point: Vec2) -> Self {
+        Self { point, compliance: 0.0 }
+    }
+
+    pub fn with_compliance(mut self, compliance: f32) -> Self {
+        self.compliance = compliance.clamp(0.0, MAX_CONSTRAINT_COMPLIANCE);
+        self
+    }
+
+    /// Shift that moves `position` toward the point this tick, given `1 / dt²`.
+    pub fn correction(&self, position: Vec2, inv_dt_sq: f32) -> Vec2 {
+        let alpha = self.compliance * inv_dt_sq;
+        (self.point - position) / (1.0 + alpha)
+    }
+}
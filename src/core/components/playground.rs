@@ -1,13 +1,71 @@
 //! Playground boundary resource.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::core::constants::*;
+use crate::core::utils::{closest_point_on_polygon, point_in_polygon, segment_intersection};
 
-/// Defines the rectangular playground area in world space.
+/// Outline of the walkable area.
+#[derive(Clone, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
+pub enum PlaygroundShape {
+    /// The full `half_size` rectangle, inset by the border.
+    #[default]
+    Rectangle,
+    /// A bowl reaching `half_size.y` from the origin, inset by the border.
+    Circle,
+    /// A closed outline in units of `half_size.y`, so resizing scales it; may be concave.
+    Polygon(Vec<Vec2>),
+}
+
+impl PlaygroundShape {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlaygroundShape::Rectangle => "Rectangle",
+            PlaygroundShape::Circle => "Circle",
+            PlaygroundShape::Polygon(_) => "Polygon",
+        }
+    }
+
+    /// A wide cave with an overhang on each side.
+    pub fn cave() -> Self {
+        PlaygroundShape::Polygon(CAVE_OUTLINE.to_vec())
+    }
+}
+
+/// What happens to nodes that reach the edge of the playground.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum BoundaryMode {
+    /// Walls push nodes back in and creatures steer away from them.
+    #[default]
+    Bounce,
+    /// Leaving one edge of the inner rectangle re-enters from the opposite edge.
+    Wrap,
+    /// No walls at all.
+    Open,
+}
+
+impl BoundaryMode {
+    pub const ALL: [BoundaryMode; 3] = [BoundaryMode::Bounce, BoundaryMode::Wrap, BoundaryMode::Open];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BoundaryMode::Bounce => "Bounce",
+            BoundaryMode::Wrap => "Wrap",
+            BoundaryMode::Open => "Open",
+        }
+    }
+}
+
+/// Defines the playground area in world space.
 #[derive(Resource, Clone, Debug, Reflect)]
 pub struct Playground {
-    pub half_size: Vec2,
+    half_size: Vec2,
+    shape: PlaygroundShape,
+    /// World-space outline of a polygon `shape`, rebuilt whenever the shape or size changes.
+    #[reflect(ignore)]
+    outline: Vec<Vec2>,
+    pub boundary_mode: BoundaryMode,
     pub border_margin: f32,
     pub stroke_width: f32,
     pub impact_damping: f32,
@@ -20,6 +78,9 @@ impl Default for Playground {
         let width = height * aspect;
         Self {
             half_size: Vec2::new(width, height),
+            shape: PlaygroundShape::Rectangle,
+            outline: Vec::new(),
+            boundary_mode: BoundaryMode::Bounce,
             border_margin: BORDER_MARGIN,
             stroke_width: STROKE_WIDTH,
             impact_damping: IMPACT_DAMPING,
@@ -28,6 +89,24 @@ impl Default for Playground {
 }
 
 impl Playground {
+    pub fn half_size(&self) -> Vec2 {
+        self.half_size
+    }
+
+    pub fn shape(&self) -> &PlaygroundShape {
+        &self.shape
+    }
+
+    pub fn set_half_size(&mut self, half_size: Vec2) {
+        self.half_size = half_size;
+        self.rebuild_outline();
+    }
+
+    pub fn set_shape(&mut self, shape: PlaygroundShape) {
+        self.shape = shape;
+        self.rebuild_outline();
+    }
+
     pub fn stroke_outer_min(&self) -> Vec2 {
         Self::calculate_outer_bound(-self.half_size, self.border_margin)
     }
@@ -44,6 +123,147 @@ impl Playground {
         Self::calculate_inner_bound(self.half_size, self.border_margin, self.stroke_width)
     }
 
+    /// Whether nodes collide with and steer away from the playground walls.
+    pub fn has_walls(&self) -> bool {
+        self.boundary_mode == BoundaryMode::Bounce
+    }
+
+    /// Size of the inner rectangle, which is the period of a wrapping playground.
+    pub fn wrap_period(&self) -> Vec2 {
+        self.inner_max() - self.inner_min()
+    }
+
+    /// Offset that brings `point` back into the inner rectangle across the seam.
+    pub fn wrap_offset(&self, point: Vec2) -> Vec2 {
+        let period = self.wrap_period();
+        -period * ((point - self.inner_min()) / period).floor()
+    }
+
+    /// Shortest form of `delta` when wrapping, so neighbors across the seam read as close.
+    pub fn wrap_delta(&self, delta: Vec2) -> Vec2 {
+        if self.boundary_mode != BoundaryMode::Wrap {
+            return delta;
+        }
+
+        let period = self.wrap_period();
+        delta - period * (delta / period).round()
+    }
+
+    /// Offsets at which something spanning `min..max` also shows on the far side of the seam.
+    pub fn wrap_images(&self, min: Vec2, max: Vec2) -> Vec<Vec2> {
+        if self.boundary_mode != BoundaryMode::Wrap {
+            return Vec::new();
+        }
+
+        let (inner_min, inner_max) = (self.inner_min(), self.inner_max());
+        let period = self.wrap_period();
+        let mut images = Vec::new();
+        for x in [-1.0, 0.0, 1.0] {
+            for y in [-1.0, 0.0, 1.0] {
+                let offset = Vec2::new(x, y) * period;
+                let (lo, hi) = (min + offset, max + offset);
+                if offset != Vec2::ZERO && lo.x < inner_max.x && hi.x > inner_min.x && lo.y < inner_max.y && hi.y > inner_min.y {
+                    images.push(offset);
+                }
+            }
+        }
+        images
+    }
+
+    /// Radius of the walkable area of a circular playground.
+    pub fn inner_radius(&self) -> f32 {
+        self.half_size.y - self.border_margin - self.stroke_width
+    }
+
+    /// World-space outline of a polygon playground.
+    pub fn polygon_points(&self) -> Option<&[Vec2]> {
+        match &self.shape {
+            PlaygroundShape::Polygon(_) => Some(&self.outline),
+            _ => None,
+        }
+    }
+
+    /// Distance from `point` to the nearest wall, negative once outside, and the inward normal there.
+    pub fn wall_distance(&self, point: Vec2) -> (f32, Vec2) {
+        match &self.shape {
+            PlaygroundShape::Rectangle => {
+                let (min, max) = (self.inner_min(), self.inner_max());
+                [
+                    (point.x - min.x, Vec2::X),
+                    (max.x - point.x, Vec2::NEG_X),
+                    (point.y - min.y, Vec2::Y),
+                    (max.y - point.y, Vec2::NEG_Y),
+                ]
+                .into_iter()
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+                .unwrap()
+            }
+            PlaygroundShape::Circle => {
+                let length = point.length();
+                let normal = if length > f32::EPSILON { -point / length } else { Vec2::NEG_X };
+                (self.inner_radius() - length, normal)
+            }
+            PlaygroundShape::Polygon(_) => {
+                let points = &self.outline;
+                let closest = closest_point_on_polygon(points, point);
+                let distance = point.distance(closest);
+                if point_in_polygon(points, point) {
+                    (distance, (point - closest).normalize_or_zero())
+                } else {
+                    (-distance, (closest - point).normalize_or_zero())
+                }
+            }
+        }
+    }
+
+    /// First point where `start..end` leaves the walkable area, or `end` if it never does.
+    pub fn clip_ray(&self, start: Vec2, end: Vec2) -> Vec2 {
+        match &self.shape {
+            PlaygroundShape::Rectangle => ray_cast_aabb(start, end, self.inner_min(), self.inner_max()),
+            PlaygroundShape::Circle => {
+                let dir = end - start;
+                let a = dir.length_squared();
+                let b = start.dot(dir);
+                let c = start.length_squared() - self.inner_radius().powi(2);
+                let discriminant = b * b - a * c;
+                if a == 0.0 || discriminant < 0.0 {
+                    return end;
+                }
+                let t = (-b + discriminant.sqrt()) / a;
+                if (0.0..1.0).contains(&t) { start + dir * t } else { end }
+            }
+            PlaygroundShape::Polygon(_) => {
+                let points = &self.outline;
+                let t = (0..points.len())
+                    .filter_map(|i| segment_intersection(start, end, points[i], points[(i + 1) % points.len()]))
+                    .filter(|&t| t > 0.0)
+                    .fold(1.0_f32, f32::min);
+                start.lerp(end, t)
+            }
+        }
+    }
+
+    /// Moves `point` back inside the walkable area, at least `padding` from every wall.
+    pub fn clamp_inside(&self, point: Vec2, padding: f32) -> Vec2 {
+        if self.shape == PlaygroundShape::Rectangle {
+            return point.clamp(self.inner_min() + Vec2::splat(padding), self.inner_max() - Vec2::splat(padding));
+        }
+
+        let (distance, normal) = self.wall_distance(point);
+        if distance >= padding {
+            point
+        } else {
+            point + normal * (padding - distance)
+        }
+    }
+
+    fn rebuild_outline(&mut self) {
+        self.outline = match &self.shape {
+            PlaygroundShape::Polygon(points) => points.iter().map(|p| *p * self.half_size.y).collect(),
+            _ => Vec::new(),
+        };
+    }
+
     fn calculate_outer_bound(half_size: Vec2, margin_offset: f32) -> Vec2 {
         half_size + Vec2::splat(margin_offset)
     }
@@ -58,6 +278,63 @@ impl Playground {
     }
 }
 
+/// Outline for [`PlaygroundShape::cave`], counter-clockwise in units of `half_size.y`.
+const CAVE_OUTLINE: [Vec2; 12] = [
+    Vec2::new(-1.5, -0.8),
+    Vec2::new(-0.4, -0.95),
+    Vec2::new(0.6, -0.85),
+    Vec2::new(1.55, -0.6),
+    Vec2::new(1.6, 0.2),
+    Vec2::new(1.1, 0.35),
+    Vec2::new(1.3, 0.8),
+    Vec2::new(0.2, 0.9),
+    Vec2::new(-0.7, 0.7),
+    Vec2::new(-1.2, 0.85),
+    Vec2::new(-1.6, 0.3),
+    Vec2::new(-1.1, -0.1),
+];
+
+fn ray_cast_aabb(start: Vec2, end: Vec2, min: Vec2, max: Vec2) -> Vec2 {
+    let dir = end - start;
+
+    if dir.x == 0.0 && dir.y == 0.0 {
+        return end;
+    }
+
+    let mut t = 1.0;
+
+    if dir.x != 0.0 {
+        let tx1 = (min.x - start.x) / dir.x;
+        let tx2 = (max.x - start.x) / dir.x;
+        let (tmin, tmax) = if tx1 < tx2 { (tx1, tx2) } else { (tx2, tx1) };
+        if tmax < 0.0 {
+            return end;
+        }
+        if tmin > t {
+            return end;
+        }
+        if tmin > 0.0 {
+            t = t.min(tmin);
+        }
+    }
+    if dir.y != 0.0 {
+        let ty1 = (min.y - start.y) / dir.y;
+        let ty2 = (max.y - start.y) / dir.y;
+        let (tmin, tmax) = if ty1 < ty2 { (ty1, ty2) } else { (ty2, ty1) };
+        if tmax < 0.0 {
+            return end;
+        }
+        if tmin > t {
+            return end;
+        }
+        if tmin > 0.0 {
+            t = t.min(tmin);
+        }
+    }
+
+    start + dir * t
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct CellEntry {
     pub cell_x: i32,
@@ -86,3 +363,71 @@ impl Ord for CellEntry {
             .then_with(|| self.cell_y.cmp(&other.cell_y))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrapping() -> Playground {
+        Playground { boundary_mode: BoundaryMode::Wrap, ..default() }
+    }
+
+    #[test]
+    fn wrap_offset_brings_points_back_across_either_seam() {
+        let playground = wrapping();
+        let (min, max, period) = (playground.inner_min(), playground.inner_max(), playground.wrap_period());
+
+        let past_max = max + Vec2::new(5.0, 7.0);
+        assert!(playground.wrap_offset(past_max).abs_diff_eq(-period, 1e-3));
+        let past_min = min - Vec2::new(5.0, 7.0);
+        assert!(playground.wrap_offset(past_min).abs_diff_eq(period, 1e-3));
+        let past_left = Vec2::new(min.x - 1.0, 0.0);
+        assert!(playground.wrap_offset(past_left).abs_diff_eq(Vec2::new(period.x, 0.0), 1e-3));
+        assert_eq!(playground.wrap_offset(Vec2::ZERO), Vec2::ZERO);
+
+        let wrapped = past_max + playground.wrap_offset(past_max);
+        assert!(wrapped.cmpge(min).all() && wrapped.cmplt(max).all());
+    }
+
+    #[test]
+    fn wrap_delta_takes_the_short_way_across_the_seam() {
+        let playground = wrapping();
+        let period = playground.wrap_period();
+
+        let delta = Vec2::new(period.x * 0.9, -period.y * 0.8);
+        assert!(playground.wrap_delta(delta).abs_diff_eq(Vec2::new(-period.x * 0.1, period.y * 0.2), 1e-2));
+        let delta = Vec2::new(-period.x * 0.9, period.y * 0.8);
+        assert!(playground.wrap_delta(delta).abs_diff_eq(Vec2::new(period.x * 0.1, -period.y * 0.2), 1e-2));
+        let short = Vec2::new(period.x * 0.3, -period.y * 0.2);
+        assert!(playground.wrap_delta(short).abs_diff_eq(short, 1e-3));
+    }
+
+    #[test]
+    fn wrap_delta_is_untouched_without_wrapping() {
+        let playground = Playground::default();
+        let delta = playground.wrap_period() * 0.9;
+        assert_eq!(playground.wrap_delta(delta), delta);
+    }
+
+    #[test]
+    fn wrap_images_cover_every_seam_a_span_crosses() {
+        let playground = wrapping();
+        let (min, max, period) = (playground.inner_min(), playground.inner_max(), playground.wrap_period());
+
+        assert!(playground.wrap_images(Vec2::splat(-10.0), Vec2::splat(10.0)).is_empty());
+
+        let right = playground.wrap_images(Vec2::new(max.x - 5.0, -10.0), Vec2::new(max.x + 5.0, 10.0));
+        assert_eq!(right, [Vec2::new(-period.x, 0.0)]);
+        let left = playground.wrap_images(Vec2::new(min.x - 5.0, -10.0), Vec2::new(min.x + 5.0, 10.0));
+        assert_eq!(left, [Vec2::new(period.x, 0.0)]);
+
+        let corner = playground.wrap_images(max - Vec2::splat(5.0), max + Vec2::splat(5.0));
+        assert_eq!(corner.len(), 3);
+        for image in [Vec2::new(-period.x, 0.0), Vec2::new(0.0, -period.y), -period] {
+            assert!(corner.contains(&image));
+        }
+
+        let bounce = Playground::default();
+        assert!(bounce.wrap_images(max - Vec2::splat(5.0), max + Vec2::splat(5.0)).is_empty());
+    }
+}
@@ -9,12 +9,36 @@ pub const BORDER_MARGIN: f32 = 10.0;
 pub const STROKE_WIDTH: f32 = 2.0;
 pub const IMPACT_DAMPING: f32 = 0.5;
 
+// =============================================================================
+// Simulation Clock Constants
+// =============================================================================
+
+pub const DEFAULT_TICK_RATE_HZ: f64 = 60.0;
+pub const MIN_TICK_RATE_HZ: f64 = 10.0;
+pub const MAX_TICK_RATE_HZ: f64 = 240.0;
+pub const MAX_SUBSTEPS_PER_FRAME: u32 = 8;
+pub const MIN_TIME_SCALE: f32 = 0.05;
+pub const MAX_TIME_SCALE: f32 = 8.0;
+
 // =============================================================================
 // Physics Constants
 // =============================================================================
 
 pub const FOLLOW_SPEED: f32 = 5.0;
 pub const AIR_DAMPING: f32 = 0.98;
+/// Mass per unit of radius squared, so a radius-10 node weighs 1.
+pub const MASS_PER_AREA: f32 = 0.01;
+pub const MIN_NODE_MASS: f32 = 0.01;
+pub const MAX_NODE_MASS: f32 = 100.0;
+
+// =============================================================================
+// Force Field Constants
+// =============================================================================
+
+pub const DEFAULT_FIELD_RADIUS: f32 = 200.0;
+pub const DEFAULT_FIELD_STRENGTH: f32 = 400.0;
+/// Decorrelates the second turbulence axis from the first.
+pub const TURBULENCE_SEED_SALT: u64 = 0x5DEE_CE66_D1CE_4E5B;
 
 // =============================================================================
 // Constraint Constants
@@ -23,6 +47,10 @@ pub const AIR_DAMPING: f32 = 0.98;
 pub const MIN_CONSTRAINT_DISTANCE: f32 = 10.0;
 pub const MAX_CONSTRAINT_DISTANCE: f32 = 200.0;
 pub const CONSTRAINT_ITERATIONS: usize = 4;
+pub const MAX_CONSTRAINT_COMPLIANCE: f32 = 0.01;
+pub const MIN_BREAK_RATIO: f32 = 1.05;
+pub const MAX_BREAK_RATIO: f32 = 4.0;
+pub const DEFAULT_BREAK_RATIO: f32 = 1.5;
 
 // =============================================================================
 // Angle Constraint Constants
@@ -38,6 +66,10 @@ pub const STEERING_THRESHOLD: f32 = 0.001;
 pub const STEERING_STRENGTH: f32 = 0.15;
 pub const TARGET_SMOOTHING: f32 = 0.08;
 pub const HORIZONTAL_WANDER_BIAS: f32 = 0.05;
+pub const WANDER_DRIFT_NOISE: f32 = 0.23;
+pub const WANDER_DRIFT_RATE: f32 = 0.48;
+pub const WANDER_DRIFT_FREQUENCY: f32 = 0.1;
+pub const WANDER_NOISE_ROWS: f32 = 1000.0;
 
 // =============================================================================
 // Collision Avoidance Constants
@@ -52,3 +84,19 @@ pub const STEERING_RESPONSIVENESS: f32 = 4.0;
 pub const STUCK_TURN_SPEED: f32 = 2.0;
 pub const BOUNDARY_AVOIDANCE_RANGE: f32 = 60.0;
 pub const BOUNDARY_REFLECTION_FACTOR: f32 = 1.2;
+/// How far past the playground an open-world wanderer strays before the pull home is at full strength.
+pub const HOME_PULL_RANGE: f32 = 600.0;
+
+// =============================================================================
+// Gait Constants
+// =============================================================================
+
+/// Fraction of `step_threshold` that is enough to step on a cadence beat.
+pub const CADENCE_STEP_FRACTION: f32 = 0.25;
+pub const DEFAULT_CADENCE: f32 = 2.0;
+pub const MIN_CADENCE: f32 = 0.25;
+pub const MAX_CADENCE: f32 = 10.0;
+pub const DEFAULT_LEAD_FACTOR: f32 = 1.0;
+pub const DEFAULT_RELEASE_RATIO: f32 = 1.1;
+/// Default bend either way at a limb joint (150 degrees), short of folding flat onto itself.
+pub const DEFAULT_JOINT_BEND_LIMIT: f32 = std::f32::consts::PI * 5.0 / 6.0;
@@ -2,6 +2,7 @@
 
 pub mod components;
 pub mod constants;
+pub mod prefab;
 pub mod resources;
 pub mod serialization;
 pub mod systems;
@@ -9,19 +10,104 @@ pub mod utils;
 
 use bevy::prelude::*;
 
-pub use components::{AnchorMovementMode, DistanceConstraint, Limb, LimbSet, Node, NodeType, Playground, ProceduralPathType};
-pub use resources::ConstraintGraph;
-pub use serialization::{SceneData, build_scene_data, deserialize_scene, export_to_file, import_from_file, spawn_scene_data, sync_pending_imports, PendingFileOp, EXAMPLES};
+pub use components::{
+    AnchorMovementMode, BoundaryMode, ConstraintBroken, ConstraintKind, DistanceConstraint, FieldFalloff, ForceField, ForceFieldKind, GaitPattern, IkSolver, JointLimit, Limb, LimbSet,
+    Node, NodeType, Obstacle, ObstacleShape, ObstacleShapeKind, Pin, Playground, PlaygroundShape, ProceduralPathType,
+};
+pub use prefab::{Prefab, PrefabLibrary, spawn_prefab};
+pub use resources::{ConstraintGraph, PlaybackMode, PlaybackState, SimulationClock, SimulationRng, SimulationSettings};
+pub use serialization::{
+    SceneData, build_scene_data, build_subgraph_data, deserialize_scene, export_to_file, import_from_file,
+    spawn_scene_data, spawn_scene_fragment, sync_pending_imports, PendingFileOp, EXAMPLES,
+};
 pub use systems::{
-    anchor_movement_system, collision_avoidance_system, constraint_solving_system, update_constraint_graph,
-    verlet_integration_system, fabrik_solving_system, limb_builder_system
+    advance_simulation_clock, anchor_movement_system, apply_simulation_settings, apply_time_scale, boundary_wrap_system,
+    collision_avoidance_system, constraint_solving_system, consume_playback_step, update_constraint_graph,
+    verlet_integration_system, fabrik_solving_system, force_field_system, limb_builder_system, pin_solving_system, snapshot_tick_positions,
 };
 
+/// Ordered stages of the fixed-step pipeline, run once per tick in declaration order.
+///
+/// Downstream code can slot systems into a stage, e.g. custom forces with
+/// `.in_set(SimulationSet::Integrate).after(verlet_integration_system)`.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimulationSet {
+    /// Constraint graph rebuild and limb discovery.
+    Graph,
+    /// Anchor steering: wander, path following, mouse follow.
+    Steering,
+    /// Verlet integration of normal nodes.
+    Integrate,
+    /// Distance and angle constraint solving.
+    Constrain,
+    /// FABRIK limb solving and stepping.
+    Ik,
+    /// Boundary clamping, node-node push-apart and wrapping.
+    Collide,
+    /// Empty stage for work that needs the final positions of the tick.
+    Post,
+}
+
+/// Headless simulation: run state, clock configuration and the fixed-step pipeline.
+///
+/// Needs nothing beyond `MinimalPlugins`, so games can embed Sway creatures without
+/// the editor, egui or any rendering.
+#[derive(Default)]
+pub struct SimulationPlugin {
+    /// Start in [`PlaybackMode::Playing`] instead of waiting for something to call `play()`.
+    pub autoplay: bool,
+}
+
+impl Plugin for SimulationPlugin {
+    fn build(&self, app: &mut App) {
+        let mode = if self.autoplay { PlaybackMode::Playing } else { PlaybackMode::Paused };
+        app.insert_resource(PlaybackState { mode, ..default() });
+        app.init_resource::<SimulationSettings>();
+        app.init_resource::<SimulationClock>();
+        app.init_resource::<SimulationRng>();
+        app.init_resource::<ConstraintGraph>();
+        app.init_resource::<Playground>();
+        app.add_message::<ConstraintBroken>();
+
+        app.add_systems(Update, (apply_simulation_settings, apply_time_scale));
+        app.configure_sets(
+            FixedUpdate,
+            (
+                SimulationSet::Graph,
+                SimulationSet::Steering,
+                SimulationSet::Integrate,
+                SimulationSet::Constrain,
+                SimulationSet::Ik,
+                SimulationSet::Collide,
+                SimulationSet::Post,
+            )
+                .chain(),
+        );
+        app.add_systems(
+            FixedUpdate,
+            (
+                (consume_playback_step, snapshot_tick_positions, advance_simulation_clock)
+                    .chain()
+                    .before(SimulationSet::Graph),
+                (update_constraint_graph, limb_builder_system).chain().in_set(SimulationSet::Graph),
+                anchor_movement_system.in_set(SimulationSet::Steering),
+                (force_field_system, verlet_integration_system).chain().in_set(SimulationSet::Integrate),
+                (constraint_solving_system, pin_solving_system).chain().in_set(SimulationSet::Constrain),
+                fabrik_solving_system.in_set(SimulationSet::Ik),
+                (collision_avoidance_system, boundary_wrap_system).chain().in_set(SimulationSet::Collide),
+            ),
+        );
+    }
+}
+
+/// Simulation plus the file import/export and prefab plumbing used by the editor.
 pub struct CorePlugin;
 
 impl Plugin for CorePlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugins(SimulationPlugin::default());
         app.init_resource::<PendingFileOp>();
+        app.insert_resource(PrefabLibrary::load());
 
         app.add_systems(Update, sync_pending_imports);
     }
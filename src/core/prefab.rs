@@ -0,0 +1,145 @@
+//! Reusable creature prefabs stored as scene fragments.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::serialization::{SceneData, spawn_scene_fragment};
+
+/// A named scene fragment, stored centered on the origin so it can be stamped anywhere.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Prefab {
+    pub name: String,
+    pub scene: SceneData,
+}
+
+impl Prefab {
+    /// Wraps `scene`, recentering it on the origin.
+    pub fn new(name: impl Into<String>, mut scene: SceneData) -> Self {
+        let centroid = scene.centroid();
+        scene.translate(-centroid);
+        scene.seed = 0;
+        Self { name: name.into(), scene }
+    }
+}
+
+/// Prefabs available to the editor; on native they mirror the files in [`prefab_dir`].
+#[derive(Resource, Default)]
+pub struct PrefabLibrary {
+    pub prefabs: Vec<Prefab>,
+    /// Prefab placed by the next click of the stamp tool.
+    pub armed: Option<usize>,
+}
+
+impl PrefabLibrary {
+    /// Reads every prefab file from the user directory; empty on web.
+    pub fn load() -> Self {
+        let mut prefabs = read_prefab_files();
+        prefabs.sort_by(|a, b| a.name.cmp(&b.name));
+        Self { prefabs, armed: None }
+    }
+
+    /// Adds or replaces the prefab with the same name and writes it to disk on native.
+    pub fn save(&mut self, prefab: Prefab) {
+        write_prefab_file(&prefab);
+        match self.prefabs.iter_mut().find(|p| p.name == prefab.name) {
+            Some(existing) => *existing = prefab,
+            None => {
+                self.prefabs.push(prefab);
+                self.prefabs.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+        self.armed = None;
+    }
+
+    pub fn armed_prefab(&self) -> Option<&Prefab> {
+        self.armed.and_then(|i| self.prefabs.get(i))
+    }
+}
+
+/// Spawns a copy of `prefab` centered on `position`.
+pub fn spawn_prefab(commands: &mut Commands, prefab: &Prefab, position: Vec2) -> Vec<Entity> {
+    let mut scene = prefab.scene.clone();
+    scene.translate(position);
+    spawn_scene_fragment(commands, &scene)
+}
+
+/// Per-user prefab directory: `%APPDATA%\sway\prefabs` on Windows,
+/// `$XDG_DATA_HOME/sway/prefabs` (or `~/.local/share/sway/prefabs`) elsewhere.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn prefab_dir() -> Option<std::path::PathBuf> {
+    use std::path::PathBuf;
+
+    let base = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share")))
+    };
+    base.map(|dir| dir.join("sway").join("prefabs"))
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_prefab_files() -> Vec<Prefab> {
+    let Some(dir) = prefab_dir() else { return Vec::new() };
+    let Ok(entries) = std::fs::read_dir(&dir) else { return Vec::new() };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| {
+            let json = std::fs::read_to_string(&path).ok()?;
+            match serde_json::from_str::<Prefab>(&json) {
+                Ok(prefab) => Some(prefab),
+                Err(e) => {
+                    warn!("Skipping prefab {}: {e}", path.display());
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_prefab_files() -> Vec<Prefab> {
+    Vec::new()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_prefab_file(prefab: &Prefab) {
+    let Some(dir) = prefab_dir() else {
+        warn!("No user directory available; prefab '{}' kept for this session only.", prefab.name);
+        return;
+    };
+    if let Err(e) = std::fs::create_dir_all(&dir) {
+        error!("Failed to create prefab directory {}: {e}", dir.display());
+        return;
+    }
+
+    let path = dir.join(format!("{}.json", file_stem(&prefab.name)));
+    match serde_json::to_string_pretty(prefab) {
+        Ok(json) => match std::fs::write(&path, json) {
+            Ok(()) => info!("Prefab saved to {}", path.display()),
+            Err(e) => error!("Failed to write prefab file: {e}"),
+        },
+        Err(e) => error!("Failed to serialize prefab: {e}"),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_prefab_file(_prefab: &Prefab) {}
+
+/// Keeps file names portable: letters, digits, `-` and `_` only.
+#[cfg(not(target_arch = "wasm32"))]
+fn file_stem(name: &str) -> String {
+    let stem: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    if stem.is_empty() { "prefab".to_string() } else { stem }
+}
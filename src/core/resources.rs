@@ -2,6 +2,135 @@ use bevy::prelude::*;
 use std::collections::HashMap;
 
 use crate::core::components::DistanceConstraint;
+use crate::core::constants::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum PlaybackMode {
+    Playing,
+    #[default]
+    Paused,
+    /// Runs `steps_remaining` more ticks, then pauses.
+    Stepping,
+}
+
+/// Run state owned by the simulation; gates every system in the fixed-step pipeline.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct PlaybackState {
+    pub mode: PlaybackMode,
+    /// Multiplier on virtual time; ticks keep their fixed length, only their rate changes.
+    pub time_scale: f32,
+    pub steps_remaining: u32,
+}
+
+impl Default for PlaybackState {
+    fn default() -> Self {
+        Self {
+            mode: PlaybackMode::default(),
+            time_scale: 1.0,
+            steps_remaining: 0,
+        }
+    }
+}
+
+impl PlaybackState {
+    pub fn is_playing(&self) -> bool {
+        matches!(self.mode, PlaybackMode::Playing | PlaybackMode::Stepping)
+    }
+
+    pub fn play(&mut self) {
+        self.mode = PlaybackMode::Playing;
+        self.steps_remaining = 0;
+    }
+
+    pub fn pause(&mut self) {
+        self.mode = PlaybackMode::Paused;
+        self.steps_remaining = 0;
+    }
+
+    pub fn toggle(&mut self) {
+        if self.mode == PlaybackMode::Playing {
+            self.pause();
+        } else {
+            self.play();
+        }
+    }
+
+    /// Advances exactly `ticks` simulation ticks and then pauses.
+    pub fn step(&mut self, ticks: u32) {
+        if ticks == 0 {
+            return;
+        }
+        self.mode = PlaybackMode::Stepping;
+        self.steps_remaining = ticks;
+    }
+}
+
+/// Fixed-timestep configuration for the simulation schedule.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct SimulationSettings {
+    /// Simulation ticks per second.
+    pub tick_rate_hz: f64,
+    /// Upper bound on ticks run in a single frame when catching up after a slow frame.
+    pub max_substeps: u32,
+}
+
+impl Default for SimulationSettings {
+    fn default() -> Self {
+        Self {
+            tick_rate_hz: DEFAULT_TICK_RATE_HZ,
+            max_substeps: MAX_SUBSTEPS_PER_FRAME,
+        }
+    }
+}
+
+impl SimulationSettings {
+    pub fn timestep(&self) -> std::time::Duration {
+        std::time::Duration::from_secs_f64(1.0 / self.tick_rate_hz.clamp(MIN_TICK_RATE_HZ, MAX_TICK_RATE_HZ))
+    }
+}
+
+/// Simulated time, advanced only while playing so pauses don't shift procedural paths.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+pub struct SimulationClock {
+    pub tick: u64,
+    pub elapsed_secs: f32,
+}
+
+/// Seeded random source for all procedural movement; a scene and seed replay identically.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+pub struct SimulationRng {
+    pub seed: u64,
+    state: u64,
+}
+
+impl SimulationRng {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, state: seed }
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    /// SplitMix64: tiny, fast and identical on every platform.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform in `[-1, 1)`.
+    pub fn next_signed(&mut self) -> f32 {
+        self.next_f32() * 2.0 - 1.0
+    }
+}
 
 #[derive(Resource, Default)]
 pub struct ConstraintGraph {
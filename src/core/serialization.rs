@@ -4,7 +4,12 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::components::{DistanceConstraint, Limb, LimbSet, Node};
+use super::components::{
+    BoundaryMode, ConstraintKind, DistanceConstraint, ForceField, GaitPattern, IkSolver, JointLimit, Limb, LimbSet, Node, Obstacle, Pin,
+    Playground, PlaygroundShape,
+};
+use super::constants::{DEFAULT_LEAD_FACTOR, DEFAULT_RELEASE_RATIO};
+use super::resources::{SimulationClock, SimulationRng};
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_futures::spawn_local;
@@ -22,21 +27,36 @@ pub struct PendingFileOp {
     pub export_data: Option<SceneData>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct ConstraintData {
     pub node_a: usize,
     pub node_b: usize,
     pub rest_length: f32,
+    #[serde(default)]
+    pub compliance: f32,
+    #[serde(default)]
+    pub kind: ConstraintKind,
+    /// `Range` bounds; scenes saved before ranges existed fall back to the rest length.
+    #[serde(default)]
+    pub min_length: Option<f32>,
+    #[serde(default)]
+    pub max_length: Option<f32>,
+    #[serde(default)]
+    pub break_ratio: Option<f32>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct LimbData {
     pub joints: Vec<usize>,
     pub target: Vec2,
     pub lengths: Vec<f32>,
+    #[serde(default)]
+    pub solver: IkSolver,
     pub iterations: usize,
     pub tolerance: f32,
     pub flip_bend: Vec<bool>,
+    #[serde(default)]
+    pub joint_limits: Vec<JointLimit>,
     pub target_node: Option<usize>,
     pub max_reach: f32,
     pub target_direction_offset: f32,
@@ -47,12 +67,38 @@ pub struct LimbData {
     pub step_start: Vec2,
     pub step_dest: Vec2,
     pub step_progress: f32,
+    #[serde(default = "default_lead_factor")]
+    pub lead_factor: f32,
+    #[serde(default = "default_release_ratio")]
+    pub release_ratio: f32,
+    #[serde(default)]
+    pub phase: usize,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+fn default_lead_factor() -> f32 {
+    DEFAULT_LEAD_FACTOR
+}
+
+fn default_release_ratio() -> f32 {
+    DEFAULT_RELEASE_RATIO
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct LimbSetData {
     pub body_node: usize,
     pub limbs: Vec<LimbData>,
+    #[serde(default)]
+    pub gait: GaitPattern,
+    #[serde(default)]
+    pub cadence: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PinData {
+    pub node: usize,
+    pub point: Vec2,
+    #[serde(default)]
+    pub compliance: f32,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -63,6 +109,20 @@ pub struct SceneData {
     pub constraints: Vec<ConstraintData>,
     #[serde(default)]
     pub limb_sets: Vec<LimbSetData>,
+    #[serde(default)]
+    pub pins: Vec<PinData>,
+    #[serde(default)]
+    pub force_fields: Vec<ForceField>,
+    #[serde(default)]
+    pub obstacles: Vec<Obstacle>,
+    /// Seed for `SimulationRng`; the same scene and seed replay identically.
+    #[serde(default)]
+    pub seed: u64,
+    /// Arena outline; scenes saved before arenas had shapes use the plain rectangle.
+    #[serde(default)]
+    pub shape: PlaygroundShape,
+    #[serde(default)]
+    pub boundary_mode: BoundaryMode,
 }
 
 static IMPORT_CHANNEL: std::sync::LazyLock<
@@ -75,27 +135,90 @@ static IMPORT_CHANNEL: std::sync::LazyLock<
     (tx, std::sync::Mutex::new(rx))
 });
 
+impl SceneData {
+    /// Mean node position, or zero for an empty scene.
+    pub fn centroid(&self) -> Vec2 {
+        if self.nodes.is_empty() {
+            return Vec2::ZERO;
+        }
+        self.nodes.iter().map(|n| n.position).sum::<Vec2>() / self.nodes.len() as f32
+    }
+
+    /// Moves every stored position, including path centers and limb targets, by `offset`.
+    pub fn translate(&mut self, offset: Vec2) {
+        for node in &mut self.nodes {
+            node.position += offset;
+            node.prev_position += offset;
+            node.target_position += offset;
+            node.path_center += offset;
+        }
+        for limb in self.limb_sets.iter_mut().flat_map(|set| set.limbs.iter_mut()) {
+            limb.target += offset;
+            limb.step_start += offset;
+            limb.step_dest += offset;
+        }
+        for pin in &mut self.pins {
+            pin.point += offset;
+        }
+        for field in &mut self.force_fields {
+            field.position += offset;
+        }
+        for obstacle in &mut self.obstacles {
+            obstacle.position += offset;
+        }
+    }
+}
+
 pub fn build_scene_data(
     nodes: &Query<(Entity, &mut Node)>,
     constraints: &Query<(Entity, &DistanceConstraint)>,
     limb_sets: &Query<(Entity, &mut LimbSet)>,
+    pins: &Query<(Entity, &Pin)>,
+    force_fields: &Query<(Entity, &mut ForceField)>,
+    obstacles: &Query<&Obstacle>,
+    playground: &Playground,
+    seed: u64,
 ) -> SceneData {
-    let (entity_list, node_list) = extract_node_data(nodes);
-    let entity_map: HashMap<Entity, usize> = entity_list.iter().enumerate().map(|(i, &e)| (e, i)).collect();
-    let constraint_list = extract_constraint_data(constraints, &entity_map);
-    let limb_set_list = extract_limb_set_data(limb_sets, &entity_map);
+    let mut scene = build_scene_subset(nodes, constraints, limb_sets, pins, seed, |_| true);
+    scene.force_fields = force_fields.iter().map(|(_, field)| field.clone()).collect();
+    scene.obstacles = obstacles.iter().cloned().collect();
+    scene.shape = playground.shape().clone();
+    scene.boundary_mode = playground.boundary_mode;
+    scene
+}
 
-    SceneData {
-        nodes: node_list,
-        constraints: constraint_list,
-        limb_sets: limb_set_list,
-    }
+/// Captures only `selected` nodes, the constraints between them and limb sets fully inside them.
+pub fn build_subgraph_data(
+    nodes: &Query<(Entity, &mut Node)>,
+    constraints: &Query<(Entity, &DistanceConstraint)>,
+    limb_sets: &Query<(Entity, &mut LimbSet)>,
+    pins: &Query<(Entity, &Pin)>,
+    selected: &[Entity],
+) -> SceneData {
+    build_scene_subset(nodes, constraints, limb_sets, pins, 0, |e| selected.contains(&e))
 }
 
+/// Spawns the scene, restores its arena and restarts the simulation clock and RNG from the scene's seed.
 pub fn spawn_scene_data(commands: &mut Commands, scene: &SceneData) -> Vec<Entity> {
+    commands.insert_resource(SimulationRng::new(scene.seed));
+    commands.insert_resource(SimulationClock::default());
+    let (shape, boundary_mode) = (scene.shape.clone(), scene.boundary_mode);
+    commands.queue(move |world: &mut World| {
+        let mut playground = world.resource_mut::<Playground>();
+        playground.set_shape(shape);
+        playground.boundary_mode = boundary_mode;
+    });
+    spawn_scene_fragment(commands, scene)
+}
+
+/// Spawns the scene's entities alongside whatever already exists, leaving simulation state alone.
+pub fn spawn_scene_fragment(commands: &mut Commands, scene: &SceneData) -> Vec<Entity> {
     let node_entities = spawn_nodes(commands, scene);
     spawn_constraints(commands, scene, &node_entities);
     spawn_limb_sets(commands, scene, &node_entities);
+    spawn_pins(commands, scene, &node_entities);
+    spawn_force_fields(commands, scene);
+    spawn_obstacles(commands, scene);
     node_entities
 }
 
@@ -187,13 +310,41 @@ pub fn deserialize_scene(json: &str) -> Option<SceneData> {
 // Private Methods
 // =============================================================================
 
-fn extract_node_data(nodes: &Query<(Entity, &mut Node)>) -> (Vec<Entity>, Vec<Node>) {
+fn build_scene_subset(
+    nodes: &Query<(Entity, &mut Node)>,
+    constraints: &Query<(Entity, &DistanceConstraint)>,
+    limb_sets: &Query<(Entity, &mut LimbSet)>,
+    pins: &Query<(Entity, &Pin)>,
+    seed: u64,
+    include: impl Fn(Entity) -> bool,
+) -> SceneData {
+    let (entity_list, node_list) = extract_node_data(nodes, include);
+    let entity_map: HashMap<Entity, usize> = entity_list.iter().enumerate().map(|(i, &e)| (e, i)).collect();
+    let constraint_list = extract_constraint_data(constraints, &entity_map);
+    let limb_set_list = extract_limb_set_data(limb_sets, &entity_map);
+    let pin_list = extract_pin_data(pins, &entity_map);
+
+    SceneData {
+        nodes: node_list,
+        constraints: constraint_list,
+        limb_sets: limb_set_list,
+        pins: pin_list,
+        force_fields: Vec::new(),
+        obstacles: Vec::new(),
+        seed,
+        shape: PlaygroundShape::default(),
+        boundary_mode: BoundaryMode::default(),
+    }
+}
+
+fn extract_node_data(nodes: &Query<(Entity, &mut Node)>, include: impl Fn(Entity) -> bool) -> (Vec<Entity>, Vec<Node>) {
     let mut entity_list = Vec::new();
     let mut node_list = Vec::new();
 
-    for (entity, node) in nodes.iter() {
+    for (entity, node) in nodes.iter().filter(|(entity, _)| include(*entity)) {
         entity_list.push(entity);
-        node_list.push(node.clone());
+        // Leave out per-tick state serde skips, so captures of the same scene compare equal.
+        node_list.push(Node { force: Vec2::ZERO, tick_start_position: None, wander_noise_row: None, ..node.clone() });
     }
 
     (entity_list, node_list)
@@ -205,20 +356,51 @@ fn extract_constraint_data(
 ) -> Vec<ConstraintData> {
     constraints
         .iter()
-        .map(|(_, c)| ConstraintData {
-            node_a: *entity_map.get(&c.node_a).unwrap_or(&0),
-            node_b: *entity_map.get(&c.node_b).unwrap_or(&0),
-            rest_length: c.rest_length,
+        .filter_map(|(_, c)| {
+            Some(ConstraintData {
+                node_a: *entity_map.get(&c.node_a)?,
+                node_b: *entity_map.get(&c.node_b)?,
+                rest_length: c.rest_length,
+                compliance: c.compliance,
+                kind: c.kind,
+                min_length: Some(c.min_length),
+                max_length: Some(c.max_length),
+                break_ratio: c.break_ratio,
+            })
         })
         .collect()
 }
 
+fn extract_pin_data(pins: &Query<(Entity, &Pin)>, entity_map: &HashMap<Entity, usize>) -> Vec<PinData> {
+    let mut pin_list: Vec<PinData> = pins
+        .iter()
+        .filter_map(|(entity, pin)| {
+            Some(PinData {
+                node: *entity_map.get(&entity)?,
+                point: pin.point,
+                compliance: pin.compliance,
+            })
+        })
+        .collect();
+    pin_list.sort_by_key(|pin| pin.node);
+    pin_list
+}
+
 fn extract_limb_set_data(
     limb_sets: &Query<(Entity, &mut LimbSet)>,
     entity_map: &HashMap<Entity, usize>,
 ) -> Vec<LimbSetData> {
+    let is_mapped = |e: &Entity| entity_map.contains_key(e);
+
     limb_sets
         .iter()
+        .filter(|(entity, limb_set)| {
+            is_mapped(entity)
+                && limb_set
+                    .limbs
+                    .iter()
+                    .all(|l| l.joints.iter().all(is_mapped) && l.target_node.is_none_or(|t| is_mapped(&t)))
+        })
         .map(|(entity, limb_set)| LimbSetData {
             body_node: *entity_map.get(&entity).unwrap_or(&0),
             limbs: limb_set
@@ -232,9 +414,11 @@ fn extract_limb_set_data(
                         .collect(),
                     target: l.target,
                     lengths: l.lengths.clone(),
+                    solver: l.solver,
                     iterations: l.iterations,
                     tolerance: l.tolerance,
                     flip_bend: l.flip_bend.clone(),
+                    joint_limits: l.joint_limits.clone(),
                     target_node: l.target_node.map(|e| *entity_map.get(&e).unwrap_or(&0)),
                     max_reach: l.max_reach,
                     target_direction_offset: l.target_direction_offset,
@@ -245,8 +429,13 @@ fn extract_limb_set_data(
                     step_start: l.step_start,
                     step_dest: l.step_dest,
                     step_progress: l.step_progress,
+                    lead_factor: l.lead_factor,
+                    release_ratio: l.release_ratio,
+                    phase: l.phase,
                 })
                 .collect(),
+            gait: limb_set.gait,
+            cadence: limb_set.cadence,
         })
         .collect()
 }
@@ -267,13 +456,41 @@ fn spawn_constraints(commands: &mut Commands, scene: &SceneData, node_entities:
 
         let node_a = node_entities[constraint.node_a];
         let node_b = node_entities[constraint.node_b];
+        let min_length = constraint.min_length.unwrap_or(constraint.rest_length);
+        let max_length = constraint.max_length.unwrap_or(constraint.rest_length);
         commands.spawn((
             Name::new("Distance Constraint"),
-            DistanceConstraint::new(node_a, node_b, constraint.rest_length),
+            DistanceConstraint::new(node_a, node_b, constraint.rest_length)
+                .with_compliance(constraint.compliance)
+                .with_kind(constraint.kind, min_length, max_length)
+                .with_break_ratio(constraint.break_ratio),
         ));
     }
 }
 
+fn spawn_pins(commands: &mut Commands, scene: &SceneData, node_entities: &[Entity]) {
+    for pin in &scene.pins {
+        let Some(&node) = node_entities.get(pin.node) else {
+            continue;
+        };
+        commands
+            .entity(node)
+            .insert(Pin::new(pin.point).with_compliance(pin.compliance));
+    }
+}
+
+fn spawn_force_fields(commands: &mut Commands, scene: &SceneData) {
+    for field in &scene.force_fields {
+        commands.spawn((Name::new("Force Field"), field.clone()));
+    }
+}
+
+fn spawn_obstacles(commands: &mut Commands, scene: &SceneData) {
+    for obstacle in &scene.obstacles {
+        commands.spawn((Name::new("Obstacle"), obstacle.clone()));
+    }
+}
+
 fn spawn_limb_sets(commands: &mut Commands, scene: &SceneData, node_entities: &[Entity]) {
     for limb_set_data in &scene.limb_sets {
         if limb_set_data.body_node >= node_entities.len() {
@@ -292,9 +509,11 @@ fn spawn_limb_sets(commands: &mut Commands, scene: &SceneData, node_entities: &[
                     .collect(),
                 target: l.target,
                 lengths: l.lengths.clone(),
+                solver: l.solver,
                 iterations: l.iterations,
                 tolerance: l.tolerance,
                 flip_bend: l.flip_bend.clone(),
+                joint_limits: l.joint_limits.clone(),
                 target_node: l.target_node.and_then(|i| node_entities.get(i).copied()),
                 max_reach: l.max_reach,
                 target_direction_offset: l.target_direction_offset,
@@ -305,12 +524,17 @@ fn spawn_limb_sets(commands: &mut Commands, scene: &SceneData, node_entities: &[
                 step_start: l.step_start,
                 step_dest: l.step_dest,
                 step_progress: l.step_progress,
+                lead_factor: l.lead_factor,
+                release_ratio: l.release_ratio,
+                phase: l.phase,
             })
             .collect();
 
-        commands
-            .entity(body_entity)
-            .insert(LimbSet { limbs });
+        commands.entity(body_entity).insert(LimbSet {
+            limbs,
+            gait: limb_set_data.gait,
+            cadence: limb_set_data.cadence,
+        });
     }
 }
 
@@ -333,3 +557,26 @@ fn parse_scene(json: &str, path: &std::path::Path) -> Option<SceneData> {
     info!("Scene imported from {}", path.display());
     Some(scene)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scenes_without_an_arena_load_the_plain_rectangle() {
+        let scene = deserialize_scene("{}").unwrap();
+        assert_eq!(scene.shape, PlaygroundShape::Rectangle);
+        assert_eq!(scene.boundary_mode, BoundaryMode::Bounce);
+    }
+
+    #[test]
+    fn arena_shape_and_boundary_mode_survive_a_round_trip() {
+        let mut scene = deserialize_scene("{}").unwrap();
+        scene.shape = PlaygroundShape::cave();
+        scene.boundary_mode = BoundaryMode::Wrap;
+
+        let loaded = deserialize_scene(&serialize_scene(&scene).unwrap()).unwrap();
+        assert_eq!(loaded.shape, PlaygroundShape::cave());
+        assert_eq!(loaded.boundary_mode, BoundaryMode::Wrap);
+    }
+}
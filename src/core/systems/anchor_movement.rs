@@ -2,17 +2,19 @@ use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 use std::collections::HashMap;
 
-use crate::core::components::{AnchorMovementMode, Node, NodeType, Playground, ProceduralPathType};
+use crate::core::components::{AnchorMovementMode, BoundaryMode, Node, NodeType, Obstacle, Playground, PlaygroundShape, ProceduralPathType};
 use crate::core::constants::*;
-use crate::core::resources::ConstraintGraph;
-use crate::core::utils::{get_mouse_world_position, normalize_angle};
-use crate::ui::state::PlaybackState;
+use crate::core::resources::{ConstraintGraph, PlaybackState, SimulationClock, SimulationRng};
+use crate::core::utils::{get_mouse_world_position, normalize_angle, value_noise};
 
 pub fn anchor_movement_system(
     playback: Res<PlaybackState>,
     time: Res<Time>,
+    clock: Res<SimulationClock>,
+    mut rng: ResMut<SimulationRng>,
     playground: Res<Playground>,
     graph: Res<ConstraintGraph>,
+    obstacle_query: Query<&Obstacle>,
     window_query: Query<&Window, With<PrimaryWindow>>,
     camera_query: Query<(&Camera, &GlobalTransform)>,
     mut anchors: Query<(Entity, &mut Node)>,
@@ -21,8 +23,9 @@ pub fn anchor_movement_system(
         return;
     }
 
-    let total_time = time.elapsed_secs();
+    let total_time = clock.elapsed_secs;
     let mouse_world = get_mouse_world_position(&window_query, &camera_query);
+    let obstacles: Vec<&Obstacle> = obstacle_query.iter().collect();
 
     let mut all_nodes_cache: Option<Vec<(Entity, Vec2, f32)>> = None;
     let mut node_positions: HashMap<Entity, Vec2> = HashMap::new();
@@ -52,7 +55,7 @@ pub fn anchor_movement_system(
             AnchorMovementMode::Procedural => {
                 let dt = time.delta_secs();
                 if let Some(ref all_nodes) = all_nodes_cache {
-                    update_procedural_target(entity, &mut node, total_time, dt, &playground, &graph, all_nodes);
+                    update_procedural_target(entity, &mut node, total_time, dt, &mut rng, &playground, &graph, all_nodes, &obstacles);
                 }
                 move_toward_target(entity, &mut node, &graph, &node_positions);
             }
@@ -108,16 +111,18 @@ fn update_procedural_target(
     node: &mut Node,
     time: f32,
     dt: f32,
+    rng: &mut SimulationRng,
     playground: &Playground,
     graph: &ConstraintGraph,
     all_nodes: &[(Entity, Vec2, f32)],
+    obstacles: &[&Obstacle],
 ) {
     let t = time + node.path_phase;
 
     node.target_position = match node.path_type {
         ProceduralPathType::Circle => calculate_circle_target(node, t),
         ProceduralPathType::Wave => calculate_wave_target(node, t),
-        ProceduralPathType::Wander => calculate_wander_target(entity, node, t, dt, playground, graph, all_nodes),
+        ProceduralPathType::Wander => calculate_wander_target(entity, node, t, dt, rng, playground, graph, all_nodes, obstacles),
     };
 }
 
@@ -138,37 +143,48 @@ fn calculate_wander_target(
     node: &mut Node,
     t: f32,
     dt: f32,
+    rng: &mut SimulationRng,
     playground: &Playground,
     graph: &ConstraintGraph,
     all_nodes: &[(Entity, Vec2, f32)],
+    obstacles: &[&Obstacle],
 ) -> Vec2 {
     let bounds = calculate_safe_bounds(playground, node.radius);
     let amplitude = node.path_amplitude.x;
 
-    apply_natural_drift(node, t);
+    apply_natural_drift(node, t, dt, rng);
 
     let wander_angle = calculate_wander_angle(node, t);
     let direction = Vec2::new(wander_angle.cos(), wander_angle.sin());
 
-    apply_lookahead_steering(entity, node, direction, amplitude, dt, &bounds, graph, all_nodes);
+    apply_lookahead_steering(entity, node, direction, amplitude, dt, &bounds, graph, all_nodes, obstacles);
+    if playground.boundary_mode == BoundaryMode::Open {
+        apply_home_steering(node, playground, dt);
+    }
 
     let mut new_target = calculate_new_target(node, t, amplitude);
 
-    handle_boundary_cases(node, &mut new_target, &bounds, dt);
+    if playground.has_walls() {
+        handle_boundary_cases(node, &mut new_target, &bounds, dt);
+    }
     handle_stuck_detection(node, &new_target, dt);
 
     smooth_target_position(node, new_target)
 }
 
-fn calculate_safe_bounds(playground: &Playground, radius: f32) -> SafeBounds {
+fn calculate_safe_bounds(playground: &Playground, radius: f32) -> SafeBounds<'_> {
     SafeBounds {
         min: playground.inner_min() + Vec2::splat(radius),
         max: playground.inner_max() - Vec2::splat(radius),
+        playground,
+        radius,
     }
 }
 
-fn apply_natural_drift(node: &mut Node, t: f32) {
-    let direction_drift = (t * 0.3).sin() * 0.15 + (t * 0.17).sin() * 0.08;
+/// Slowly turns the wander direction by seeded noise over simulation time, each node on its own row.
+fn apply_natural_drift(node: &mut Node, t: f32, dt: f32, rng: &mut SimulationRng) {
+    let row = *node.wander_noise_row.get_or_insert_with(|| rng.next_f32() * WANDER_NOISE_ROWS);
+    let direction_drift = value_noise(Vec2::new(t * WANDER_DRIFT_FREQUENCY, row), rng.seed) * WANDER_DRIFT_NOISE;
     let current_angle = node.wander_direction;
     let horizontal_pull = if current_angle.abs() < std::f32::consts::FRAC_PI_2 {
         -current_angle * HORIZONTAL_WANDER_BIAS
@@ -177,7 +193,7 @@ fn apply_natural_drift(node: &mut Node, t: f32) {
         (target - current_angle) * HORIZONTAL_WANDER_BIAS
     };
 
-    node.wander_direction += (direction_drift * 0.008) + horizontal_pull;
+    node.wander_direction += direction_drift * WANDER_DRIFT_RATE * dt + horizontal_pull;
 }
 
 fn calculate_wander_angle(node: &Node, t: f32) -> f32 {
@@ -194,6 +210,7 @@ fn apply_lookahead_steering(
     bounds: &SafeBounds,
     graph: &ConstraintGraph,
     all_nodes: &[(Entity, Vec2, f32)],
+    obstacles: &[&Obstacle],
 ) {
     let speed_lookahead = node.movement_speed * LOOKAHEAD_WINDOW;
     let base_lookahead = amplitude;
@@ -215,10 +232,12 @@ fn apply_lookahead_steering(
         let distance_factor = (scan_dist / base_lookahead).max(1.0);
         let base_strength = STEERING_STRENGTH / distance_factor;
 
-        let boundary_steering = calculate_boundary_steering(scan_point, bounds, wander_angle, base_strength);
+        if bounds.playground.has_walls() {
+            let boundary_steering = calculate_boundary_steering(scan_point, bounds, wander_angle, base_strength);
 
-        if boundary_steering.abs() > STEERING_THRESHOLD {
-            total_steering += boundary_steering;
+            if boundary_steering.abs() > STEERING_THRESHOLD {
+                total_steering += boundary_steering;
+            }
         }
 
         let node_steering = calculate_node_steering(
@@ -235,6 +254,12 @@ fn apply_lookahead_steering(
         if node_steering.abs() > STEERING_THRESHOLD {
             total_steering += node_steering;
         }
+
+        let obstacle_steering = calculate_obstacle_steering(scan_point, node.radius, wander_angle, obstacles, base_strength);
+
+        if obstacle_steering.abs() > STEERING_THRESHOLD {
+            total_steering += obstacle_steering;
+        }
     }
 
     if total_steering.abs() > STEERING_THRESHOLD {
@@ -244,6 +269,20 @@ fn apply_lookahead_steering(
     }
 }
 
+/// Open worlds have no walls, so wanderers that stray past the playground are gradually turned back toward it.
+fn apply_home_steering(node: &mut Node, playground: &Playground, dt: f32) {
+    let home_point = node.position.clamp(playground.inner_min(), playground.inner_max());
+    let to_home = home_point - node.position;
+    let stray = to_home.length();
+    if stray < MIN_TARGET_DISTANCE {
+        return;
+    }
+
+    let pull = (stray / HOME_PULL_RANGE).min(1.0).powi(2);
+    node.wander_direction = steer_smoothly(node.wander_direction, to_home.to_angle(), STUCK_TURN_SPEED * pull * dt);
+    node.wander_direction = normalize_angle(node.wander_direction);
+}
+
 fn calculate_new_target(node: &Node, t: f32, amplitude: f32) -> Vec2 {
     let angle_variation = (t * 0.7).sin() * 0.15 + (t * 1.3).sin() * 0.08;
     let final_angle = node.wander_direction + angle_variation;
@@ -252,6 +291,11 @@ fn calculate_new_target(node: &Node, t: f32, amplitude: f32) -> Vec2 {
 }
 
 fn handle_boundary_cases(node: &mut Node, new_target: &mut Vec2, bounds: &SafeBounds, dt: f32) {
+    if *bounds.playground.shape() != PlaygroundShape::Rectangle {
+        handle_wall_boundary(node, new_target, bounds, dt);
+        return;
+    }
+
     let out_left = new_target.x < bounds.min.x;
     let out_right = new_target.x > bounds.max.x;
     let out_bottom = new_target.y < bounds.min.y;
@@ -318,6 +362,18 @@ fn handle_single_axis_boundary(
     clamp_to_bounds(new_target, bounds);
 }
 
+/// Turns back inward once the target crosses a curved or polygonal wall, then pulls it inside.
+fn handle_wall_boundary(node: &mut Node, new_target: &mut Vec2, bounds: &SafeBounds, dt: f32) {
+    let (distance, inward) = bounds.playground.wall_distance(*new_target);
+    if distance >= bounds.radius {
+        return;
+    }
+
+    let turn_amount = STUCK_TURN_SPEED * 4.0 * dt;
+    node.wander_direction = steer_smoothly(node.wander_direction, inward.to_angle(), turn_amount);
+    *new_target = bounds.playground.clamp_inside(*new_target, bounds.radius);
+}
+
 fn handle_stuck_detection(node: &mut Node, new_target: &Vec2, dt: f32) {
     let distance_to_target = (node.position - *new_target).length();
 
@@ -336,6 +392,10 @@ fn calculate_boundary_steering(point: Vec2, bounds: &SafeBounds, current_angle:
     let mut steering = 0.0_f32;
     let avoidance_strength = strength * 2.5;
 
+    if *bounds.playground.shape() != PlaygroundShape::Rectangle {
+        return calculate_wall_steering(point, bounds, current_angle, avoidance_strength);
+    }
+
     if point.x < bounds.min.x + BOUNDARY_AVOIDANCE_RANGE {
         let dist = (point.x - bounds.min.x).max(0.1);
         let weight = (1.0 - (dist / BOUNDARY_AVOIDANCE_RANGE).min(1.0)).powi(2);
@@ -361,6 +421,20 @@ fn calculate_boundary_steering(point: Vec2, bounds: &SafeBounds, current_angle:
     steering
 }
 
+/// Boundary steering for curved and polygonal walls: slide along the nearest wall, tilted inward.
+fn calculate_wall_steering(point: Vec2, bounds: &SafeBounds, current_angle: f32, avoidance_strength: f32) -> f32 {
+    let (distance, inward) = bounds.playground.wall_distance(point);
+    let dist = (distance - bounds.radius).max(0.1);
+    if dist >= BOUNDARY_AVOIDANCE_RANGE {
+        return 0.0;
+    }
+
+    let heading = Vec2::from_angle(current_angle);
+    let tangent = if inward.perp().dot(heading) >= 0.0 { inward.perp() } else { -inward.perp() };
+    let weight = (1.0 - dist / BOUNDARY_AVOIDANCE_RANGE).powi(2);
+    angle_diff((tangent + inward).to_angle(), current_angle) * avoidance_strength * weight
+}
+
 fn calculate_node_steering(
     self_entity: Entity,
     self_pos: Vec2,
@@ -395,6 +469,33 @@ fn calculate_node_steering(
     steering
 }
 
+/// Turns away from any obstacle the scan point comes near, sliding along its surface.
+fn calculate_obstacle_steering(
+    scan_point: Vec2,
+    self_radius: f32,
+    current_angle: f32,
+    obstacles: &[&Obstacle],
+    strength: f32,
+) -> f32 {
+    let heading = Vec2::from_angle(current_angle);
+    let min_safe = self_radius + NODE_AVOIDANCE_BUFFER;
+    let mut steering = 0.0_f32;
+
+    for obstacle in obstacles {
+        let (distance, normal) = obstacle.signed_distance(scan_point);
+        if distance >= min_safe {
+            continue;
+        }
+
+        let tangent = if normal.perp().dot(heading) >= 0.0 { normal.perp() } else { -normal.perp() };
+        let urgency = (1.0 - distance / min_safe).min(2.0);
+        let away_angle = (tangent + normal).to_angle();
+        steering += angle_diff(away_angle, current_angle) * strength * urgency;
+    }
+
+    steering
+}
+
 fn should_skip_node(other_entity: Entity, self_entity: Entity, my_group: Option<u32>, graph: &ConstraintGraph) -> bool {
     if other_entity == self_entity {
         return true;
@@ -442,7 +543,9 @@ fn steer_smoothly(current: f32, target: f32, fraction: f32) -> f32 {
     current + angle_diff(target, current) * fraction
 }
 
-struct SafeBounds {
+struct SafeBounds<'a> {
     min: Vec2,
     max: Vec2,
+    playground: &'a Playground,
+    radius: f32,
 }
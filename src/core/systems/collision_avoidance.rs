@@ -1,9 +1,8 @@
 use bevy::prelude::*;
 
-use crate::core::components::{CellEntry, Node, NodeType, Playground};
+use crate::core::components::{CellEntry, Node, NodeType, Obstacle, Playground, PlaygroundShape};
 use crate::core::constants::{CELL_SIZE, MIN_COLLISION_DISTANCE};
-use crate::core::resources::ConstraintGraph;
-use crate::ui::state::PlaybackState;
+use crate::core::resources::{ConstraintGraph, PlaybackState};
 
 use std::collections::HashSet;
 
@@ -12,6 +11,7 @@ struct Collider {
     entity: Entity,
     position: Vec2,
     radius: f32,
+    inverse_mass: f32,
     group: Option<u32>,
 }
 
@@ -19,22 +19,25 @@ pub fn collision_avoidance_system(
     playback: Res<PlaybackState>,
     playground: Res<Playground>,
     graph: Res<ConstraintGraph>,
+    obstacles: Query<&Obstacle>,
     mut nodes: Query<(Entity, &mut Node)>,
 ) {
     if !playback.is_playing() {
         return;
     }
 
-    let mut colliders = collect_colliders(&mut nodes, &playground, &graph);
-    let mut grid_entries = generate_grid_entries(&colliders);
+    let mut colliders = collect_colliders(&mut nodes, &playground, &graph, &obstacles);
+    let mut grid_entries = generate_grid_entries(&colliders, &playground);
 
     grid_entries.sort_unstable();
 
     let potential_pairs = find_potential_pairs(&grid_entries);
 
-    let pairs_vec: Vec<(usize, usize)> = potential_pairs.into_iter().collect();
+    // HashSet order varies per process; sort so resolution order is reproducible.
+    let mut pairs_vec: Vec<(usize, usize)> = potential_pairs.into_iter().collect();
+    pairs_vec.sort_unstable();
 
-    resolve_collisions(&mut colliders, &pairs_vec);
+    resolve_collisions(&mut colliders, &pairs_vec, &playground);
     apply_updates(&mut nodes, &colliders);
 }
 
@@ -46,6 +49,7 @@ fn collect_colliders(
     nodes: &mut Query<(Entity, &mut Node)>,
     playground: &Playground,
     graph: &ConstraintGraph,
+    obstacles: &Query<&Obstacle>,
 ) -> Vec<Collider> {
     let inner_min = playground.inner_min();
     let inner_max = playground.inner_max();
@@ -56,40 +60,63 @@ fn collect_colliders(
             continue;
         }
 
-        apply_boundary_collision(&mut node, inner_min, inner_max);
+        if playground.has_walls() {
+            if *playground.shape() == PlaygroundShape::Rectangle {
+                apply_boundary_collision(&mut node, inner_min, inner_max);
+            } else {
+                let (distance, normal) = playground.wall_distance(node.position);
+                push_out_of_surface(&mut node, distance, normal);
+            }
+        }
+        for obstacle in obstacles.iter() {
+            apply_obstacle_collision(&mut node, obstacle);
+        }
 
         colliders.push(Collider {
             entity,
             position: node.position,
             radius: node.radius,
+            inverse_mass: node.inverse_mass(),
             group: graph.get_group(entity),
         });
     }
     colliders
 }
 
-fn generate_grid_entries(colliders: &[Collider]) -> Vec<CellEntry> {
+fn generate_grid_entries(colliders: &[Collider], playground: &Playground) -> Vec<CellEntry> {
     let mut grid_entries = Vec::with_capacity(colliders.len() * 4);
+    let max_radius = colliders.iter().map(|c| c.radius).fold(0.0_f32, f32::max);
 
     for (index, collider) in colliders.iter().enumerate() {
-        let min_x = ((collider.position.x - collider.radius) / CELL_SIZE).floor() as i32;
-        let max_x = ((collider.position.x + collider.radius) / CELL_SIZE).floor() as i32;
-        let min_y = ((collider.position.y - collider.radius) / CELL_SIZE).floor() as i32;
-        let max_y = ((collider.position.y + collider.radius) / CELL_SIZE).floor() as i32;
-
-        for x in min_x..=max_x {
-            for y in min_y..=max_y {
-                grid_entries.push(CellEntry {
-                    cell_x: x,
-                    cell_y: y,
-                    collider_index: index,
-                });
-            }
+        push_cell_entries(&mut grid_entries, index, collider.position, collider.radius);
+
+        // Near the seam of a wrapping playground, also file the collider under its mirrored
+        // position so neighbors on the far edge end up in the same cell.
+        let reach = Vec2::splat(collider.radius + max_radius);
+        for offset in playground.wrap_images(collider.position - reach, collider.position + reach) {
+            push_cell_entries(&mut grid_entries, index, collider.position + offset, collider.radius);
         }
     }
     grid_entries
 }
 
+fn push_cell_entries(grid_entries: &mut Vec<CellEntry>, index: usize, position: Vec2, radius: f32) {
+    let min_x = ((position.x - radius) / CELL_SIZE).floor() as i32;
+    let max_x = ((position.x + radius) / CELL_SIZE).floor() as i32;
+    let min_y = ((position.y - radius) / CELL_SIZE).floor() as i32;
+    let max_y = ((position.y + radius) / CELL_SIZE).floor() as i32;
+
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            grid_entries.push(CellEntry {
+                cell_x: x,
+                cell_y: y,
+                collider_index: index,
+            });
+        }
+    }
+}
+
 fn find_potential_pairs(grid_entries: &[CellEntry]) -> HashSet<(usize, usize)> {
     let mut potential_pairs = HashSet::with_capacity(grid_entries.len());
     let mut start_index = 0;
@@ -107,6 +134,9 @@ fn find_potential_pairs(grid_entries: &[CellEntry]) -> HashSet<(usize, usize)> {
             for j in (i + 1)..end_index {
                 let idx_a = grid_entries[i].collider_index;
                 let idx_b = grid_entries[j].collider_index;
+                if idx_a == idx_b {
+                    continue;
+                }
 
                 let (first, second) = if idx_a < idx_b { (idx_a, idx_b) } else { (idx_b, idx_a) };
 
@@ -120,7 +150,7 @@ fn find_potential_pairs(grid_entries: &[CellEntry]) -> HashSet<(usize, usize)> {
     potential_pairs
 }
 
-fn resolve_collisions(colliders: &mut [Collider], potential_pairs: &[(usize, usize)]) {
+fn resolve_collisions(colliders: &mut [Collider], potential_pairs: &[(usize, usize)], playground: &Playground) {
     let iterations = 4;
 
     for _ in 0..iterations {
@@ -137,9 +167,12 @@ fn resolve_collisions(colliders: &mut [Collider], potential_pairs: &[(usize, usi
                 }
             }
 
-            if let Some(push) = calculate_collision_push(col_a.position, col_a.radius, col_b.position, col_b.radius) {
-                col_a.position += push;
-                col_b.position -= push;
+            let other_pos = col_a.position - playground.wrap_delta(col_a.position - col_b.position);
+            if let Some(push) = calculate_collision_push(col_a.position, col_a.radius, other_pos, col_b.radius) {
+                // Split the separation by inverse mass so the lighter node gets shoved further.
+                let share_a = col_a.inverse_mass / (col_a.inverse_mass + col_b.inverse_mass);
+                col_a.position += push * share_a;
+                col_b.position -= push * (1.0 - share_a);
                 any_correction = true;
             }
         }
@@ -198,6 +231,28 @@ fn apply_verlet_bounce(node: &mut Node, inner_min: Vec2, inner_max: Vec2, radius
     node.prev_position = node.position - new_velocity;
 }
 
+fn apply_obstacle_collision(node: &mut Node, obstacle: &Obstacle) {
+    let (distance, normal) = obstacle.signed_distance(node.position);
+    push_out_of_surface(node, distance, normal);
+}
+
+/// Pushes the node out along `normal` until it is `radius` clear of the surface, reflecting the inbound velocity.
+fn push_out_of_surface(node: &mut Node, distance: f32, normal: Vec2) {
+    let penetration = node.radius - distance;
+    if penetration <= 0.0 {
+        return;
+    }
+
+    node.position += normal * penetration;
+
+    let velocity = node.position - node.prev_position;
+    let inbound = velocity.dot(normal);
+    if inbound < 0.0 {
+        let new_velocity = velocity - normal * inbound * (2.0 - node.collision_damping);
+        node.prev_position = node.position - new_velocity;
+    }
+}
+
 fn calculate_collision_push(pos: Vec2, radius: f32, other_pos: Vec2, other_radius: f32) -> Option<Vec2> {
     let delta = pos - other_pos;
     let distance_sq = delta.length_squared();
@@ -208,7 +263,7 @@ fn calculate_collision_push(pos: Vec2, radius: f32, other_pos: Vec2, other_radiu
         let distance = distance_sq.sqrt();
         let overlap = min_distance - distance;
         let separation_dir = delta / distance;
-        Some(separation_dir * overlap * 0.5)
+        Some(separation_dir * overlap)
     } else {
         None
     }
@@ -1,38 +1,64 @@
 //! Iterative distance-constraint solver.
+//!
+//! Constraints are resolved XPBD-style: each carries a compliance that is scaled by
+//! the squared timestep, so soft links behave the same at any tick rate.
 
 use bevy::prelude::*;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
-use crate::core::components::{DistanceConstraint, Node, NodeType};
+use crate::core::components::{ConstraintBroken, DistanceConstraint, Node, NodeType};
 use crate::core::constants::*;
-use crate::core::resources::ConstraintGraph;
+use crate::core::resources::{ConstraintGraph, PlaybackState};
 use crate::core::utils::constrain_angle;
-use crate::ui::state::PlaybackState;
 
 pub fn constraint_solving_system(
+    mut commands: Commands,
     playback: Res<PlaybackState>,
+    time: Res<Time>,
     graph: Res<ConstraintGraph>,
-    constraint_query: Query<&DistanceConstraint>,
+    constraint_query: Query<(Entity, &DistanceConstraint)>,
     mut nodes: Query<&mut Node>,
+    mut broken_writer: MessageWriter<ConstraintBroken>,
 ) {
     if !playback.is_playing() {
         return;
     }
 
-    let constraints: Vec<&DistanceConstraint> = constraint_query.iter().collect();
+    let broken = break_overstretched(&mut commands, &constraint_query, &nodes, &mut broken_writer);
+
+    let constraints: Vec<&DistanceConstraint> = constraint_query
+        .iter()
+        .map(|(_, c)| c)
+        .filter(|c| !broken.contains(&ordered_pair(c.node_a, c.node_b)))
+        .collect();
     if constraints.is_empty() {
         return;
     }
 
-    let (chains, standalone) = build_chains(&graph, &constraints, &nodes);
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+    let inv_dt_sq = 1.0 / (dt * dt);
+
+    // The graph only catches up next tick, so drop broken links now or their chains would snap them back.
+    let adjacency = if broken.is_empty() {
+        Cow::Borrowed(&graph.adjacency)
+    } else {
+        Cow::Owned(without_broken_links(&graph.adjacency, &broken))
+    };
+    let (chains, standalone) = build_chains(&adjacency, &constraints, &nodes);
 
     for chain in &chains {
-        resolve_chain(chain, &mut nodes);
+        resolve_chain(chain, inv_dt_sq, &mut nodes);
     }
 
+    // Accumulated XPBD multipliers, one per standalone constraint, reset every tick.
+    let mut lambdas = vec![0.0; standalone.len()];
     for _ in 0..CONSTRAINT_ITERATIONS {
-        for constraint in &standalone {
-            solve_distance(constraint, &mut nodes);
+        for (constraint, lambda) in standalone.iter().zip(lambdas.iter_mut()) {
+            solve_distance(constraint, lambda, inv_dt_sq, &mut nodes);
         }
     }
 }
@@ -41,17 +67,80 @@ pub fn constraint_solving_system(
 // Private Methods
 // =============================================================================
 
+/// Despawns constraints stretched past their break ratio and reports each one.
+/// Returns the node pairs they joined; the graph picks up the removal on the next tick.
+fn break_overstretched(
+    commands: &mut Commands,
+    constraint_query: &Query<(Entity, &DistanceConstraint)>,
+    nodes: &Query<&mut Node>,
+    broken_writer: &mut MessageWriter<ConstraintBroken>,
+) -> HashSet<(Entity, Entity)> {
+    let mut broken = HashSet::new();
+
+    for (entity, constraint) in constraint_query.iter() {
+        let Some((a, b)) = extract_constraint_endpoints(constraint, nodes) else {
+            continue;
+        };
+        if !constraint.is_overstretched(a.position.distance(b.position)) {
+            continue;
+        }
+
+        commands.entity(entity).despawn();
+        broken_writer.write(ConstraintBroken {
+            constraint: entity,
+            node_a: constraint.node_a,
+            node_b: constraint.node_b,
+        });
+        broken.insert(ordered_pair(constraint.node_a, constraint.node_b));
+    }
+
+    broken
+}
+
+/// Copy of `adjacency` without the edges between `broken` node pairs.
+fn without_broken_links(
+    adjacency: &HashMap<Entity, Vec<(Entity, f32)>>,
+    broken: &HashSet<(Entity, Entity)>,
+) -> HashMap<Entity, Vec<(Entity, f32)>> {
+    adjacency
+        .iter()
+        .map(|(&entity, neighbors)| {
+            let kept = neighbors
+                .iter()
+                .filter(|(neighbor, _)| !broken.contains(&ordered_pair(entity, *neighbor)))
+                .copied()
+                .collect();
+            (entity, kept)
+        })
+        .collect()
+}
+
 struct ChainLink {
     entity: Entity,
     rest_length: f32,
+    /// Constraint joining this link to the next one, if any.
+    constraint: Option<DistanceConstraint>,
+}
+
+impl ChainLink {
+    fn compliance(&self) -> f32 {
+        self.constraint.as_ref().map_or(0.0, |c| c.compliance)
+    }
+
+    /// Length to hold the next link at, given its current distance from this one.
+    fn target_length(&self, distance: f32) -> f32 {
+        match &self.constraint {
+            Some(c) => c.target_length(distance).unwrap_or(distance),
+            None => self.rest_length,
+        }
+    }
 }
 
 fn build_chains(
-    graph: &ConstraintGraph,
+    adj: &HashMap<Entity, Vec<(Entity, f32)>>,
     constraints: &[&DistanceConstraint],
     nodes: &Query<&mut Node>,
 ) -> (Vec<Vec<ChainLink>>, Vec<DistanceConstraint>) {
-    let adj = &graph.adjacency;
     let mut visited: HashSet<Entity> = HashSet::new();
     let mut chains: Vec<Vec<ChainLink>> = Vec::new();
 
@@ -84,12 +173,32 @@ fn build_chains(
     }
 
     collect_cycles(adj, &mut visited, &mut chains, nodes);
+    assign_link_constraints(&mut chains, constraints);
 
     let standalone = find_standalone_constraints(constraints, &visited);
 
     (chains, standalone)
 }
 
+/// The graph only stores rest lengths, so look each link's full constraint up by node pair.
+fn assign_link_constraints(chains: &mut [Vec<ChainLink>], constraints: &[&DistanceConstraint]) {
+    let constraint_by_pair: HashMap<(Entity, Entity), &DistanceConstraint> = constraints
+        .iter()
+        .map(|c| (ordered_pair(c.node_a, c.node_b), *c))
+        .collect();
+
+    for chain in chains.iter_mut() {
+        for i in 0..chain.len().saturating_sub(1) {
+            let pair = ordered_pair(chain[i].entity, chain[i + 1].entity);
+            chain[i].constraint = constraint_by_pair.get(&pair).map(|c| (*c).clone());
+        }
+    }
+}
+
+fn ordered_pair(a: Entity, b: Entity) -> (Entity, Entity) {
+    if a < b { (a, b) } else { (b, a) }
+}
+
 fn find_chain_starts(adj: &HashMap<Entity, Vec<(Entity, f32)>>, nodes: &Query<&mut Node>) -> Vec<Entity> {
     let mut starts: Vec<Entity> = Vec::new();
     let mut non_anchor_leaves: Vec<Entity> = Vec::new();
@@ -102,6 +211,9 @@ fn find_chain_starts(adj: &HashMap<Entity, Vec<(Entity, f32)>>, nodes: &Query<&m
         }
     }
 
+    // HashMap order varies per process; sort so solve order is reproducible.
+    starts.sort_unstable();
+    non_anchor_leaves.sort_unstable();
     starts.extend(non_anchor_leaves);
     starts
 }
@@ -139,6 +251,7 @@ fn build_chain_from_endpoint(
     let mut chain: Vec<ChainLink> = vec![ChainLink {
         entity: start,
         rest_length: rest_len,
+        constraint: None,
     }];
     visited.insert(start);
 
@@ -158,6 +271,7 @@ fn build_chain_from_endpoint(
             chain.push(ChainLink {
                 entity: current,
                 rest_length: next_rest,
+                constraint: None,
             });
             visited.insert(current);
             prev = current;
@@ -166,6 +280,7 @@ fn build_chain_from_endpoint(
             chain.push(ChainLink {
                 entity: current,
                 rest_length: 0.0,
+                constraint: None,
             });
             visited.insert(current);
             break;
@@ -238,6 +353,7 @@ fn build_cycle_chain(
         chain.push(ChainLink {
             entity: current,
             rest_length: rest_len,
+            constraint: None,
         });
         visited.insert(current);
         prev = current;
@@ -247,6 +363,7 @@ fn build_cycle_chain(
             chain.push(ChainLink {
                 entity: current,
                 rest_length: 0.0,
+                constraint: None,
             });
             break;
         }
@@ -290,7 +407,7 @@ fn find_standalone_constraints(
         .collect()
 }
 
-fn resolve_chain(chain: &[ChainLink], nodes: &mut Query<&mut Node>) {
+fn resolve_chain(chain: &[ChainLink], inv_dt_sq: f32, nodes: &mut Query<&mut Node>) {
     if chain.len() < 2 {
         return;
     }
@@ -301,7 +418,7 @@ fn resolve_chain(chain: &[ChainLink], nodes: &mut Query<&mut Node>) {
     };
 
     for i in 1..chain.len() {
-        let result = resolve_chain_link(&chain[i], &chain[i - 1], prev_pos, prev_angle, nodes);
+        let result = resolve_chain_link(&chain[i], &chain[i - 1], prev_pos, prev_angle, inv_dt_sq, nodes);
 
         if let Some((new_angle, new_pos)) = result {
             prev_angle = new_angle;
@@ -322,6 +439,7 @@ fn resolve_chain_link(
     prev_link: &ChainLink,
     prev_pos: Vec2,
     prev_angle: f32,
+    inv_dt_sq: f32,
     nodes: &mut Query<&mut Node>,
 ) -> Option<(f32, Vec2)> {
     let (cur_pos, is_fixed, angle_min, angle_max) = {
@@ -340,7 +458,8 @@ fn resolve_chain_link(
             prev_angle,
             angle_min,
             angle_max,
-            prev_link.rest_length,
+            prev_link.target_length(cur_pos.distance(prev_pos)),
+            prev_link.compliance() * inv_dt_sq,
             nodes,
         )
     }
@@ -366,72 +485,88 @@ fn handle_movable_in_chain(
     prev_angle: f32,
     angle_min: f32,
     angle_max: f32,
-    rest_length: f32,
+    length: f32,
+    alpha: f32,
     nodes: &mut Query<&mut Node>,
 ) -> Option<(f32, Vec2)> {
     let cur_angle = (cur_pos - prev_pos).to_angle();
     let constrained_angle = constrain_angle(cur_angle, prev_angle, angle_min, angle_max);
-    let target = prev_pos + Vec2::from_angle(constrained_angle) * rest_length;
-    let shift = target - cur_pos;
+    let target = prev_pos + Vec2::from_angle(constrained_angle) * length;
+    // The previous link is already settled, so this node carries the whole correction:
+    // a single XPBD step with unit inverse mass scales it by 1 / (1 + alpha).
+    let shift = (target - cur_pos) / (1.0 + alpha);
+    let new_pos = cur_pos + shift;
 
     if let Ok(mut node) = nodes.get_mut(entity) {
-        node.position = target;
+        node.position = new_pos;
         node.prev_position += shift;
         node.chain_angle = constrained_angle;
     }
 
-    Some((constrained_angle, target))
+    Some((constrained_angle, new_pos))
 }
 
-fn solve_distance(constraint: &DistanceConstraint, nodes: &mut Query<&mut Node>) {
-    let (pos_a, pos_b, type_a, type_b) = match extract_constraint_positions(constraint, nodes) {
+fn solve_distance(constraint: &DistanceConstraint, lambda: &mut f32, inv_dt_sq: f32, nodes: &mut Query<&mut Node>) {
+    let (a, b) = match extract_constraint_endpoints(constraint, nodes) {
         Some(data) => data,
         None => return,
     };
 
-    let delta = pos_b - pos_a;
+    let delta = b.position - a.position;
     let dist = delta.length();
     if dist < 1e-6 {
         return;
     }
 
-    let correction = calculate_position_correction(delta, dist, constraint.rest_length);
-    let (w_a, w_b) = calculate_constraint_weights(type_a, type_b);
+    let Some(length) = constraint.target_length(dist) else {
+        return;
+    };
 
-    if w_a == 0.0 && w_b == 0.0 {
+    let (w_a, w_b) = calculate_inverse_masses(&a, &b);
+    let w_sum = w_a + w_b;
+    if w_sum == 0.0 {
         return;
     }
 
-    apply_constraint_correction(constraint.node_a, correction * w_a, nodes);
-    apply_constraint_correction(constraint.node_b, -correction * w_b, nodes);
+    let alpha = constraint.compliance * inv_dt_sq;
+    let delta_lambda = calculate_delta_lambda(dist - length, *lambda, w_sum, alpha);
+    *lambda += delta_lambda;
+
+    let direction = delta / dist;
+    apply_constraint_correction(constraint.node_a, -direction * delta_lambda * w_a, nodes);
+    apply_constraint_correction(constraint.node_b, direction * delta_lambda * w_b, nodes);
 }
 
-fn extract_constraint_positions(
-    constraint: &DistanceConstraint,
-    nodes: &Query<&mut Node>,
-) -> Option<(Vec2, Vec2, NodeType, NodeType)> {
+struct Endpoint {
+    position: Vec2,
+    node_type: NodeType,
+    inverse_mass: f32,
+}
+
+fn extract_constraint_endpoints(constraint: &DistanceConstraint, nodes: &Query<&mut Node>) -> Option<(Endpoint, Endpoint)> {
+    let endpoint = |node: &Node| Endpoint {
+        position: node.position,
+        node_type: node.node_type,
+        inverse_mass: node.inverse_mass(),
+    };
     let a = nodes.get(constraint.node_a).ok()?;
     let b = nodes.get(constraint.node_b).ok()?;
-    Some((a.position, b.position, a.node_type, b.node_type))
+    Some((endpoint(a), endpoint(b)))
 }
 
-fn calculate_position_correction(delta: Vec2, dist: f32, rest_length: f32) -> Vec2 {
-    delta / dist * (dist - rest_length)
+/// XPBD multiplier update for a scalar constraint error `c`, given the accumulated `lambda`.
+fn calculate_delta_lambda(c: f32, lambda: f32, w_sum: f32, alpha: f32) -> f32 {
+    (-c - alpha * lambda) / (w_sum + alpha)
 }
 
-fn calculate_constraint_weights(type_a: NodeType, type_b: NodeType) -> (f32, f32) {
-    if type_a == NodeType::Limb || type_b == NodeType::Limb {
+/// Anchors are immovable and limb links belong to FABRIK; everything else moves by its mass.
+fn calculate_inverse_masses(a: &Endpoint, b: &Endpoint) -> (f32, f32) {
+    if a.node_type == NodeType::Limb || b.node_type == NodeType::Limb {
         return (0.0, 0.0);
     }
 
-    let a_fixed = type_a == NodeType::Anchor;
-    let b_fixed = type_b == NodeType::Anchor;
-    match (a_fixed, b_fixed) {
-        (true, true) => (0.0, 0.0),
-        (true, false) => (0.0, 1.0),
-        (false, true) => (1.0, 0.0),
-        (false, false) => (0.5, 0.5),
-    }
+    let weight = |e: &Endpoint| if e.node_type == NodeType::Anchor { 0.0 } else { e.inverse_mass };
+    (weight(a), weight(b))
 }
 
 fn apply_constraint_correction(entity: Entity, correction: Vec2, nodes: &mut Query<&mut Node>) {
@@ -440,3 +575,57 @@ fn apply_constraint_correction(entity: Entity, correction: Vec2, nodes: &mut Que
         node.prev_position += correction;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stretch of a unit mass hanging off an anchor by a constraint of `compliance`, after
+    /// `seconds` of a constant pull, stepped the way `solve_distance` steps a standalone link.
+    fn hang(compliance: f32, pull: f32, dt: f32, seconds: f32) -> f32 {
+        let alpha = compliance / (dt * dt);
+        let (mut stretch, mut velocity) = (0.0_f32, 0.0_f32);
+        for _ in 0..(seconds / dt).round() as usize {
+            velocity += pull * dt;
+            let mut predicted = stretch + velocity * dt;
+            let mut lambda = 0.0;
+            for _ in 0..CONSTRAINT_ITERATIONS {
+                let delta_lambda = calculate_delta_lambda(predicted, lambda, 1.0, alpha);
+                lambda += delta_lambda;
+                predicted += delta_lambda;
+            }
+            velocity = (predicted - stretch) / dt;
+            stretch = predicted;
+        }
+        stretch
+    }
+
+    #[test]
+    fn rigid_constraint_corrects_the_whole_error_in_one_step() {
+        assert_eq!(calculate_delta_lambda(2.0, 0.0, 1.0, 0.0), -2.0);
+        assert_eq!(calculate_delta_lambda(2.0, 0.0, 2.0, 0.0), -1.0);
+    }
+
+    #[test]
+    fn compliance_softens_the_correction() {
+        // An alpha equal to the inverse mass halves it.
+        assert_eq!(calculate_delta_lambda(2.0, 0.0, 1.0, 1.0), -1.0);
+        assert!(calculate_delta_lambda(2.0, 0.0, 1.0, 9.0) > -0.5);
+    }
+
+    #[test]
+    fn rigid_constraint_holds_at_any_tick_rate() {
+        for dt in [1.0 / 30.0, 1.0 / 60.0, 1.0 / 240.0] {
+            assert!(hang(0.0, 1000.0, dt, 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn soft_constraint_settles_at_the_same_stretch_at_any_tick_rate() {
+        let (compliance, pull) = (1e-4, 1000.0);
+        for dt in [1.0 / 30.0, 1.0 / 60.0, 1.0 / 240.0] {
+            let stretch = hang(compliance, pull, dt, 2.0);
+            assert!((stretch - compliance * pull).abs() < 1e-3, "dt {dt}: stretch {stretch}");
+        }
+    }
+}
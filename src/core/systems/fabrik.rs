@@ -2,10 +2,10 @@
 
 use bevy::prelude::*;
 
-use crate::core::components::{LimbSet, Node, Playground};
-use crate::core::resources::ConstraintGraph;
+use crate::core::components::{GaitPattern, IkSolver, Limb, LimbSet, Node, Obstacle, Pin, Playground};
+use crate::core::constants::CADENCE_STEP_FRACTION;
+use crate::core::resources::{ConstraintGraph, PlaybackState, SimulationClock};
 use crate::core::utils::constrain_angle;
-use crate::ui::state::PlaybackState;
 
 pub fn fabrik_solving_system(
     playback: Res<PlaybackState>,
@@ -13,16 +13,18 @@ pub fn fabrik_solving_system(
     graph: Res<ConstraintGraph>,
     mut limb_sets: Query<(Entity, &mut LimbSet)>,
     mut nodes: Query<&mut Node>,
+    pins: Query<&Pin>,
+    obstacle_query: Query<&Obstacle>,
     time: Res<Time>,
+    clock: Res<SimulationClock>,
 ) {
     if !playback.is_playing() {
         return;
     }
 
     let dt = time.delta_secs();
-    let inner_min = playground.inner_min();
-    let inner_max = playground.inner_max();
     let graph_changed = graph.is_changed();
+    let obstacles: Vec<&Obstacle> = obstacle_query.iter().collect();
     let mut positions_buf: Vec<Vec2> = Vec::new();
 
     for (body_entity, mut limb_set) in limb_sets.iter_mut() {
@@ -32,9 +34,7 @@ pub fn fabrik_solving_system(
                 Err(_) => continue,
             };
 
-            if limb_set.limbs[limb_idx].joints.is_empty() {
-                continue;
-            }
+            let lift_threshold = calculate_lift_threshold(&limb_set, limb_idx, clock.elapsed_secs);
 
             solve_single_limb(
                 &mut limb_set.limbs[limb_idx],
@@ -42,10 +42,12 @@ pub fn fabrik_solving_system(
                 body_pos,
                 &graph,
                 &mut nodes,
-                inner_min,
-                inner_max,
+                &playground,
+                &obstacles,
                 graph_changed,
                 dt,
+                &pins,
+                lift_threshold,
                 &mut positions_buf,
             );
         }
@@ -57,15 +59,17 @@ pub fn fabrik_solving_system(
 // =============================================================================
 
 fn solve_single_limb(
-    limb: &mut crate::core::components::Limb,
+    limb: &mut Limb,
     body_entity: Entity,
     body_pos: Vec2,
     graph: &ConstraintGraph,
     nodes: &mut Query<&mut Node>,
-    inner_min: Vec2,
-    inner_max: Vec2,
+    playground: &Playground,
+    obstacles: &[&Obstacle],
     graph_changed: bool,
     dt: f32,
+    pins: &Query<&Pin>,
+    lift_threshold: Option<f32>,
     positions: &mut Vec<Vec2>,
 ) {
     let joint_count = limb.joints.len();
@@ -78,15 +82,24 @@ fn solve_single_limb(
     }
 
     let ideal_target =
-        compute_ideal_target(limb, body_entity, body_pos, graph, nodes, inner_min, inner_max);
+        compute_ideal_target(limb, body_entity, body_pos, graph, nodes, playground, obstacles, dt);
 
     if limb.target == Vec2::ZERO {
         limb.target = ideal_target;
     }
 
-    update_stepping(limb, ideal_target, dt);
+    let inv_dt_sq = 1.0 / (dt * dt).max(f32::EPSILON);
+    let foot_pin = limb.joints.last().and_then(|&foot| pins.get(foot).ok());
+
+    // A planted foot left too far behind breaks contact and steps now, even if the gait would hold it down.
+    let total_length: f32 = limb.lengths.iter().sum();
+    let contact = resolve_foot_target(limb, foot_pin, playground, inv_dt_sq);
+    let overextended = limb.is_planted() && contact.distance(body_pos) > total_length * limb.release_ratio;
+    let lift_threshold = if overextended { Some(0.0) } else { lift_threshold };
 
-    let target = clamp_target(limb.target, inner_min, inner_max);
+    update_stepping(limb, ideal_target, dt, lift_threshold);
+
+    let target = resolve_foot_target(limb, foot_pin, playground, inv_dt_sq);
 
     let chain_len = joint_count + 1;
     positions.clear();
@@ -104,61 +117,26 @@ fn solve_single_limb(
         return;
     }
 
-    let total_length: f32 = limb.lengths.iter().sum();
     let dist_to_target = (target - body_pos).length();
 
     if dist_to_target >= total_length {
+        // A planted foot holds its contact point until it lets go, stretching every segment evenly to reach it.
+        let stretch = if limb.is_planted() { dist_to_target / total_length.max(f32::EPSILON) } else { 1.0 };
         let dir = (target - body_pos).normalize_or_zero();
         for i in 0..limb.lengths.len() {
-            positions[i + 1] = positions[i] + dir * limb.lengths[i];
+            positions[i + 1] = positions[i] + dir * limb.lengths[i] * stretch;
         }
     } else {
-        for _ in 0..limb.iterations {
-            let last = positions.len() - 1;
-            positions[last] = target;
-            for i in (0..limb.lengths.len()).rev() {
-                positions[i] =
-                    constrain_distance(positions[i], positions[i + 1], limb.lengths[i]);
-            }
-
-            positions[0] = body_pos;
-            let mut prev_angle = if let Ok(body_node) = nodes.get(body_entity) {
-                body_node.chain_angle
-            } else {
-                0.0
-            };
-
-            for i in 0..limb.lengths.len() {
-                let entity = limb.joints[i];
-                if let Ok(node) = nodes.get(entity) {
-                    let current_pos = positions[i + 1];
-                    let parent_pos = positions[i];
-                    let raw_angle = (current_pos - parent_pos).to_angle();
-                    
-                    let constrained_angle = constrain_angle(
-                        raw_angle, prev_angle, node.angle_min, node.angle_max
-                    );
-                    
-                    positions[i + 1] = parent_pos + Vec2::from_angle(constrained_angle) * limb.lengths[i];
-                    prev_angle = constrained_angle;
-                } else {
-                    positions[i + 1] =
-                        constrain_distance(positions[i + 1], positions[i], limb.lengths[i]);
-                    prev_angle = (positions[i + 1] - positions[i]).to_angle();
-                }
-            }
-
-            if joint_count > 1 {
-                apply_joint_constraints(positions, limb);
-            }
-
-            let diff = positions[last].distance(target);
-            if diff <= limb.tolerance {
-                break;
-            }
+        let root_angle = nodes.get(body_entity).map_or(0.0, |body_node| body_node.chain_angle);
+        match limb.solver {
+            IkSolver::TwoBone if joint_count == 2 => solve_two_bone(positions, limb, nodes, target, root_angle),
+            IkSolver::Ccd => solve_ccd(positions, limb, nodes, target, root_angle),
+            _ => solve_fabrik(positions, limb, nodes, target, root_angle),
         }
     }
 
+    apply_joint_pins(positions, limb, pins, inv_dt_sq);
+
     for (i, &entity) in limb.joints.iter().enumerate() {
         if let Ok(mut node) = nodes.get_mut(entity) {
             node.position = positions[i + 1];
@@ -177,10 +155,157 @@ fn solve_single_limb(
     }
 }
 
+fn solve_fabrik(
+    positions: &mut [Vec2],
+    limb: &Limb,
+    nodes: &Query<&mut Node>,
+    target: Vec2,
+    root_angle: f32,
+) {
+    let root = positions[0];
+    let last = positions.len() - 1;
+
+    for _ in 0..limb.iterations {
+        positions[last] = target;
+        for i in (0..limb.lengths.len()).rev() {
+            positions[i] =
+                constrain_distance(positions[i], positions[i + 1], limb.lengths[i]);
+            if i + 1 < last {
+                positions[i] = constrain_bend_backward(positions, i, limb);
+            }
+        }
+
+        positions[0] = root;
+        apply_joint_constraints(positions, limb);
+        reach_forward(positions, limb, nodes, root_angle);
+
+        let diff = positions[last].distance(target);
+        if diff <= limb.tolerance {
+            break;
+        }
+    }
+}
+
+/// Places the knee of a two-segment limb with the law of cosines, on the side `flip_bend` asks for,
+/// then clamps both segments the same way FABRIK and CCD do.
+fn solve_two_bone(positions: &mut [Vec2], limb: &Limb, nodes: &Query<&mut Node>, target: Vec2, root_angle: f32) {
+    let root = positions[0];
+    let (upper, lower) = (limb.lengths[0], limb.lengths[1]);
+    let to_target = target - root;
+    let reach = to_target.length().clamp((upper - lower).abs(), upper + lower).max(f32::EPSILON);
+
+    let cos_root = ((upper * upper + reach * reach - lower * lower) / (2.0 * upper * reach)).clamp(-1.0, 1.0);
+    let side = if limb.flip_bend.first().copied().unwrap_or(false) { 1.0 } else { -1.0 };
+    let upper_angle = constrain_segment_angle(to_target.to_angle() + side * cos_root.acos(), root_angle, 0, limb, nodes);
+    positions[1] = root + Vec2::from_angle(upper_angle) * upper;
+
+    let lower_angle = constrain_segment_angle((target - positions[1]).to_angle(), upper_angle, 1, limb, nodes);
+    positions[2] = positions[1] + Vec2::from_angle(lower_angle) * lower;
+}
+
+/// Cyclic Coordinate Descent: from the foot back to the root, swings everything past each joint
+/// so the foot lines up with the target, clamped to that joint's limits.
+fn solve_ccd(
+    positions: &mut [Vec2],
+    limb: &Limb,
+    nodes: &Query<&mut Node>,
+    target: Vec2,
+    root_angle: f32,
+) {
+    let last = positions.len() - 1;
+
+    // Start from last tick's pose, moved to the preferred side and back within limits.
+    apply_joint_constraints(positions, limb);
+    reach_forward(positions, limb, nodes, root_angle);
+
+    for _ in 0..limb.iterations {
+        for i in (0..last).rev() {
+            let pivot = positions[i];
+            let current = (positions[i + 1] - pivot).to_angle();
+            let swing = (target - pivot).to_angle() - (positions[last] - pivot).to_angle();
+            let prev_angle = if i == 0 { root_angle } else { (pivot - positions[i - 1]).to_angle() };
+            let angle = constrain_segment_angle(current + swing, prev_angle, i, limb, nodes);
+
+            let rotation = Vec2::from_angle(angle - current);
+            for position in &mut positions[i + 1..] {
+                *position = pivot + rotation.rotate(*position - pivot);
+            }
+        }
+
+        let diff = positions[last].distance(target);
+        if diff <= limb.tolerance {
+            break;
+        }
+    }
+}
+
+/// Re-lays the chain out from the root, keeping segment lengths and clamping every angle.
+fn reach_forward(positions: &mut [Vec2], limb: &Limb, nodes: &Query<&mut Node>, root_angle: f32) {
+    let mut prev_angle = root_angle;
+    for i in 0..limb.lengths.len() {
+        let parent_pos = positions[i];
+        let raw_angle = (positions[i + 1] - parent_pos).to_angle();
+        let angle = constrain_segment_angle(raw_angle, prev_angle, i, limb, nodes);
+
+        positions[i + 1] = parent_pos + Vec2::from_angle(angle) * limb.lengths[i];
+        prev_angle = angle;
+    }
+}
+
+/// Clamps the angle of segment `i` against the one before it, by the joint node's own angle
+/// range and, past the first segment, by the limb's bend limit.
+fn constrain_segment_angle(angle: f32, prev_angle: f32, i: usize, limb: &Limb, nodes: &Query<&mut Node>) -> f32 {
+    let mut angle = angle;
+    if let Ok(node) = nodes.get(limb.joints[i]) {
+        angle = constrain_angle(angle, prev_angle, node.angle_min, node.angle_max);
+    }
+    if i > 0 {
+        let limit = limb.joint_limit(i - 1);
+        angle = constrain_angle(angle, prev_angle, limit.min, limit.max);
+    }
+    angle
+}
+
+/// Where the foot is aimed: the limb target, pulled toward a foot pin as far as its compliance allows.
+fn resolve_foot_target(limb: &Limb, foot_pin: Option<&Pin>, playground: &Playground, inv_dt_sq: f32) -> Vec2 {
+    let target = foot_pin.map_or(limb.target, |pin| limb.target + pin.correction(limb.target, inv_dt_sq));
+    if playground.has_walls() { playground.clamp_inside(target, 0.0) } else { target }
+}
+
+/// Pulls pinned joints above the foot off the solved pose, the way pins on free nodes win over their bones.
+fn apply_joint_pins(positions: &mut [Vec2], limb: &Limb, pins: &Query<&Pin>, inv_dt_sq: f32) {
+    let knees = limb.joints.len().saturating_sub(1);
+    for (i, &entity) in limb.joints.iter().enumerate().take(knees) {
+        if let Ok(pin) = pins.get(entity) {
+            positions[i + 1] += pin.correction(positions[i + 1], inv_dt_sq);
+        }
+    }
+}
+
+/// How far the foot may drift before lifting this tick, or `None` if the gait holds it down.
+fn calculate_lift_threshold(limb_set: &LimbSet, index: usize, elapsed_secs: f32) -> Option<f32> {
+    let limb = &limb_set.limbs[index];
+    let beat = limb_set
+        .cadence
+        .filter(|&cadence| cadence > 0.0 && limb_set.gait != GaitPattern::Free)
+        .map(|cadence| (elapsed_secs * cadence) as u64);
+
+    if !limb_set.may_start_step(index, beat) {
+        return None;
+    }
+
+    // On its beat a limb steps early, so the gait keeps its rhythm instead of waiting on the threshold.
+    Some(match beat {
+        Some(_) => limb.step_threshold * CADENCE_STEP_FRACTION,
+        None => limb.step_threshold,
+    })
+}
+
 fn update_stepping(
-    limb: &mut crate::core::components::Limb,
+    limb: &mut Limb,
     ideal_target: Vec2,
     dt: f32,
+    lift_threshold: Option<f32>,
 ) {
     if limb.is_stepping {
         limb.step_progress += dt * limb.step_speed;
@@ -202,9 +327,9 @@ fn update_stepping(
             limb.target = flat_pos;
             limb.target.y += height_offset;
         }
-    } else {
+    } else if let Some(threshold) = lift_threshold {
         let dist = limb.target.distance(ideal_target);
-        if dist > limb.step_threshold {
+        if dist > threshold {
             limb.is_stepping = true;
             limb.step_start = limb.target;
             limb.step_dest = ideal_target;
@@ -214,7 +339,7 @@ fn update_stepping(
 }
 
 fn recalculate_lengths(
-    limb: &mut crate::core::components::Limb,
+    limb: &mut Limb,
     body_entity: Entity,
     graph: &ConstraintGraph,
 ) {
@@ -251,13 +376,14 @@ fn recalculate_lengths(
 }
 
 fn compute_ideal_target(
-    limb: &mut crate::core::components::Limb,
+    limb: &mut Limb,
     body_entity: Entity,
     body_pos: Vec2,
     _graph: &ConstraintGraph,
     nodes: &Query<&mut Node>,
-    inner_min: Vec2,
-    inner_max: Vec2,
+    playground: &Playground,
+    obstacles: &[&Obstacle],
+    dt: f32,
 ) -> Vec2 {
     if let Some(target_entity) = limb.target_node {
         if let Ok(target_node) = nodes.get(target_entity) {
@@ -265,17 +391,21 @@ fn compute_ideal_target(
         }
     }
 
-    let body_angle = if let Ok(body_node) = nodes.get(body_entity) {
-        body_node.chain_angle
+    let (body_angle, body_velocity) = if let Ok(body_node) = nodes.get(body_entity) {
+        (body_node.chain_angle, body_node.tick_velocity() / dt.max(f32::EPSILON))
     } else {
-        0.0
+        (0.0, Vec2::ZERO)
     };
 
     let target_angle = body_angle + limb.target_direction_offset;
     let target_dir = Vec2::from_angle(target_angle);
 
-    let ray_end = body_pos + target_dir * limb.max_reach;
-    ray_cast_aabb(body_pos, ray_end, inner_min, inner_max)
+    // Lead by where the body will be once a step started now has landed, keeping the foot within reach.
+    let step_duration = 1.0 / limb.step_speed.max(f32::EPSILON);
+    let lead = body_velocity * step_duration * limb.lead_factor;
+    let ray_end = body_pos + (target_dir * limb.max_reach + lead).clamp_length_max(limb.max_reach);
+    let ray_end = if playground.has_walls() { playground.clip_ray(body_pos, ray_end) } else { ray_end };
+    ray_cast_obstacles(body_pos, ray_end, obstacles)
 }
 
 fn constrain_distance(pos: Vec2, anchor: Vec2, distance: f32) -> Vec2 {
@@ -287,52 +417,30 @@ fn constrain_distance(pos: Vec2, anchor: Vec2, distance: f32) -> Vec2 {
     }
 }
 
-fn clamp_target(target: Vec2, min: Vec2, max: Vec2) -> Vec2 {
-    target.clamp(min, max)
+/// Shortens `start..end` to the first obstacle surface it crosses, ignoring any the body is already inside.
+fn ray_cast_obstacles(start: Vec2, end: Vec2, obstacles: &[&Obstacle]) -> Vec2 {
+    let t = obstacles
+        .iter()
+        .filter_map(|obstacle| obstacle.ray_cast(start, end))
+        .filter(|&t| t > 0.0)
+        .fold(1.0_f32, f32::min);
+    start.lerp(end, t)
 }
 
-fn ray_cast_aabb(start: Vec2, end: Vec2, min: Vec2, max: Vec2) -> Vec2 {
-    let dir = end - start;
-
-    if dir.x == 0.0 && dir.y == 0.0 {
-        return end;
-    }
-
-    let mut t = 1.0;
-
-    if dir.x != 0.0 {
-        let tx1 = (min.x - start.x) / dir.x;
-        let tx2 = (max.x - start.x) / dir.x;
-        let (tmin, tmax) = if tx1 < tx2 { (tx1, tx2) } else { (tx2, tx1) };
-        if tmax < 0.0 {
-            return end;
-        }
-        if tmin > t {
-            return end;
-        }
-        if tmin > 0.0 {
-            t = t.min(tmin);
-        }
-    }
-    if dir.y != 0.0 {
-        let ty1 = (min.y - start.y) / dir.y;
-        let ty2 = (max.y - start.y) / dir.y;
-        let (tmin, tmax) = if ty1 < ty2 { (ty1, ty2) } else { (ty2, ty1) };
-        if tmax < 0.0 {
-            return end;
-        }
-        if tmin > t {
-            return end;
-        }
-        if tmin > 0.0 {
-            t = t.min(tmin);
-        }
-    }
-
-    start + dir * t
+/// Swings segment `i` around its child joint so the bend there stays within the joint's limit.
+///
+/// The bend is measured from the incoming segment to the outgoing one, so walking toward the
+/// root the allowed range is mirrored.
+fn constrain_bend_backward(positions: &[Vec2], i: usize, limb: &Limb) -> Vec2 {
+    let joint = positions[i + 1];
+    let outgoing = (positions[i + 2] - joint).to_angle();
+    let limit = limb.joint_limit(i);
+    let incoming = constrain_angle((joint - positions[i]).to_angle(), outgoing, -limit.max, -limit.min);
+    joint - Vec2::from_angle(incoming) * limb.lengths[i]
 }
 
-fn apply_joint_constraints(positions: &mut [Vec2], limb: &crate::core::components::Limb) {
+/// Mirrors each joint across the root-to-tip axis onto the side picked by `flip_bend`.
+fn apply_joint_constraints(positions: &mut [Vec2], limb: &Limb) {
     if positions.len() < 3 {
         return;
     }
@@ -0,0 +1,33 @@
+//! Applies force-field accelerations ahead of integration.
+
+use bevy::prelude::*;
+
+use crate::core::components::{ForceField, Node, NodeType};
+use crate::core::resources::{PlaybackState, SimulationClock, SimulationRng};
+
+pub fn force_field_system(
+    playback: Res<PlaybackState>,
+    clock: Res<SimulationClock>,
+    rng: Res<SimulationRng>,
+    fields: Query<&ForceField>,
+    mut nodes: Query<&mut Node>,
+) {
+    if !playback.is_playing() || fields.is_empty() {
+        return;
+    }
+
+    let time = clock.elapsed_secs;
+    for mut node in nodes.iter_mut() {
+        // Only normal nodes are integrated, so only they can feel a field.
+        if node.node_type != NodeType::Normal {
+            continue;
+        }
+
+        let position = node.position;
+        let acceleration: Vec2 = fields
+            .iter()
+            .map(|field| field.acceleration_at(position, time, rng.seed))
+            .sum();
+        node.acceleration += acceleration;
+    }
+}
@@ -1,8 +1,7 @@
 use bevy::prelude::*;
 
 use crate::core::components::DistanceConstraint;
-use crate::core::resources::ConstraintGraph;
-use crate::ui::state::PlaybackState;
+use crate::core::resources::{ConstraintGraph, PlaybackState};
 
 pub fn update_constraint_graph(
     playback: Res<PlaybackState>,
@@ -10,18 +9,22 @@ pub fn update_constraint_graph(
     all_constraints: Query<&DistanceConstraint>,
     mut graph: ResMut<ConstraintGraph>,
     mut has_built: Local<bool>,
+    mut built_count: Local<usize>,
 ) {
     if !playback.is_playing() {
         *has_built = false;
         return;
     }
 
-    let should_rebuild = !*has_built || !constraints.is_empty();
+    // A shrinking count catches despawns (e.g. broken constraints), which `Changed` can't see.
+    let count = all_constraints.iter().len();
+    let should_rebuild = !*has_built || !constraints.is_empty() || count != *built_count;
     if !should_rebuild {
         return;
     }
 
     *has_built = true;
+    *built_count = count;
     let constraint_list: Vec<&DistanceConstraint> = all_constraints.iter().collect();
     graph.rebuild(&constraint_list);
 }
@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 
-use crate::core::components::{Limb, LimbSet, Node, NodeType};
+use crate::core::components::{GaitPattern, JointLimit, Limb, LimbSet, Node, NodeType};
 use crate::core::resources::ConstraintGraph;
 
 pub fn limb_builder_system(
@@ -42,6 +42,7 @@ pub fn limb_builder_system(
                 limbs_for_body.push(Limb {
                     joints: chain,
                     flip_bend: vec![false; chain_len],
+                    joint_limits: vec![JointLimit::default(); chain_len],
                     ..Default::default()
                 });
             }
@@ -82,12 +83,16 @@ pub fn limb_builder_system(
                         merged.push(new_limb);
                     }
                 }
-                commands.entity(body_entity).insert(LimbSet { limbs: merged });
+                let mut limb_set = LimbSet { limbs: merged, ..existing.clone() };
+                if limb_set.gait == GaitPattern::Alternating {
+                    limb_set.assign_alternating_phases();
+                }
+                commands.entity(body_entity).insert(limb_set);
             }
         } else {
             commands
                 .entity(body_entity)
-                .insert(LimbSet { limbs: new_limbs });
+                .insert(LimbSet::new(new_limbs));
         }
     }
 }
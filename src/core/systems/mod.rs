@@ -4,14 +4,22 @@ pub mod anchor_movement;
 pub mod collision_avoidance;
 pub mod constraint_solver;
 pub mod fabrik;
+pub mod force_field;
 pub mod graph;
 pub mod limb_builder;
 pub mod physics;
+pub mod pin;
+pub mod timestep;
 
 pub use anchor_movement::anchor_movement_system;
 pub use collision_avoidance::collision_avoidance_system;
 pub use constraint_solver::constraint_solving_system;
 pub use fabrik::fabrik_solving_system;
+pub use force_field::force_field_system;
 pub use graph::update_constraint_graph;
 pub use limb_builder::limb_builder_system;
-pub use physics::{boundary_collision_system, verlet_integration_system};
+pub use physics::{boundary_collision_system, boundary_wrap_system, verlet_integration_system};
+pub use pin::pin_solving_system;
+pub use timestep::{
+    advance_simulation_clock, apply_simulation_settings, apply_time_scale, consume_playback_step, snapshot_tick_positions,
+};
@@ -1,9 +1,11 @@
-//! Physics systems — Verlet integration and boundary collision.
+//! Physics systems — Verlet integration, boundary collision and wrapping.
 
 use bevy::prelude::*;
 
-use crate::core::components::{Node, NodeType, Playground};
-use crate::ui::state::PlaybackState;
+use crate::core::components::{BoundaryMode, LimbSet, Node, NodeType, Playground};
+use crate::core::resources::{ConstraintGraph, PlaybackState};
+
+use std::collections::HashMap;
 
 pub fn verlet_integration_system(playback: Res<PlaybackState>, time: Res<Time>, mut nodes: Query<&mut Node>) {
     if !playback.is_playing() {
@@ -17,6 +19,7 @@ pub fn verlet_integration_system(playback: Res<PlaybackState>, time: Res<Time>,
             node.verlet_step(dt);
         } else {
             node.prev_position = node.position;
+            node.force = Vec2::ZERO;
         }
     }
 }
@@ -26,7 +29,7 @@ pub fn boundary_collision_system(
     playground: Res<Playground>,
     mut nodes: Query<&mut Node>,
 ) {
-    if !playback.is_playing() {
+    if !playback.is_playing() || !playground.has_walls() {
         return;
     }
 
@@ -39,6 +42,48 @@ pub fn boundary_collision_system(
     }
 }
 
+/// Carries anything that left a wrapping playground over to the opposite edge.
+///
+/// Connected groups move as one once their centroid crosses, so constraints never
+/// span the arena; limb targets follow their body.
+pub fn boundary_wrap_system(
+    playback: Res<PlaybackState>,
+    playground: Res<Playground>,
+    graph: Res<ConstraintGraph>,
+    mut nodes: Query<(Entity, &mut Node)>,
+    mut limb_sets: Query<&mut LimbSet>,
+) {
+    if !playback.is_playing() || playground.boundary_mode != BoundaryMode::Wrap {
+        return;
+    }
+
+    let group_shifts = calculate_group_shifts(&playground, &graph, &nodes);
+
+    for (entity, mut node) in nodes.iter_mut() {
+        let shift = match graph.get_group(entity) {
+            Some(group) => group_shifts.get(&group).copied().unwrap_or(Vec2::ZERO),
+            None => playground.wrap_offset(node.position),
+        };
+        if shift != Vec2::ZERO {
+            node.translate(shift);
+        }
+    }
+
+    for mut limb_set in limb_sets.iter_mut() {
+        for limb in limb_set.limbs.iter_mut() {
+            let shift = limb
+                .joints
+                .first()
+                .and_then(|&joint| graph.get_group(joint))
+                .and_then(|group| group_shifts.get(&group).copied())
+                .unwrap_or(Vec2::ZERO);
+            if shift != Vec2::ZERO {
+                limb.translate(shift);
+            }
+        }
+    }
+}
+
 // =============================================================================
 // Private Methods
 // =============================================================================
@@ -93,3 +138,23 @@ fn clamp_axis_with_bounce(
         (pos, prev, accel)
     }
 }
+
+fn calculate_group_shifts(
+    playground: &Playground,
+    graph: &ConstraintGraph,
+    nodes: &Query<(Entity, &mut Node)>,
+) -> HashMap<u32, Vec2> {
+    let mut sums: HashMap<u32, (Vec2, f32)> = HashMap::new();
+    for (entity, node) in nodes.iter() {
+        if let Some(group) = graph.get_group(entity) {
+            let sum = sums.entry(group).or_insert((Vec2::ZERO, 0.0));
+            sum.0 += node.position;
+            sum.1 += 1.0;
+        }
+    }
+
+    sums.into_iter()
+        .map(|(group, (sum, count))| (group, playground.wrap_offset(sum / count)))
+        .filter(|(_, shift)| *shift != Vec2::ZERO)
+        .collect()
+}
@@ -0,0 +1,29 @@
+//! Pulls pinned nodes back toward their world points.
+
+use bevy::prelude::*;
+
+use crate::core::components::{Node, NodeType, Pin};
+use crate::core::resources::PlaybackState;
+
+pub fn pin_solving_system(playback: Res<PlaybackState>, time: Res<Time>, mut pinned: Query<(&mut Node, &Pin)>) {
+    if !playback.is_playing() {
+        return;
+    }
+
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+    let inv_dt_sq = 1.0 / (dt * dt);
+
+    for (mut node, pin) in pinned.iter_mut() {
+        // Limb joints are placed by the IK solver, which applies their pins itself.
+        if node.node_type == NodeType::Limb {
+            continue;
+        }
+
+        let shift = pin.correction(node.position, inv_dt_sq);
+        node.position += shift;
+        node.prev_position += shift;
+    }
+}
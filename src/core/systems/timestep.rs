@@ -0,0 +1,66 @@
+//! Fixed-timestep clock configuration and render interpolation snapshots.
+
+use bevy::prelude::*;
+
+use crate::core::components::Node;
+use crate::core::constants::{MAX_TIME_SCALE, MIN_TIME_SCALE};
+use crate::core::resources::{PlaybackMode, PlaybackState, SimulationClock, SimulationSettings};
+
+/// Pushes `SimulationSettings` into Bevy's fixed and virtual clocks whenever it changes.
+pub fn apply_simulation_settings(
+    settings: Res<SimulationSettings>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    let timestep = settings.timestep();
+    fixed_time.set_timestep(timestep);
+    virtual_time.set_max_delta(timestep * settings.max_substeps.max(1));
+}
+
+/// Applies the playback time scale to virtual time, which also paces the fixed-step loop.
+pub fn apply_time_scale(playback: Res<PlaybackState>, mut virtual_time: ResMut<Time<Virtual>>) {
+    if !playback.is_changed() {
+        return;
+    }
+
+    let scale = playback.time_scale.clamp(MIN_TIME_SCALE, MAX_TIME_SCALE);
+    if virtual_time.relative_speed() != scale {
+        virtual_time.set_relative_speed(scale);
+    }
+}
+
+/// Counts down a step request at the start of each tick, pausing once it is used up.
+pub fn consume_playback_step(mut playback: ResMut<PlaybackState>) {
+    if playback.mode != PlaybackMode::Stepping {
+        return;
+    }
+
+    if playback.steps_remaining == 0 {
+        playback.pause();
+    } else {
+        playback.steps_remaining -= 1;
+    }
+}
+
+/// Records every node's position at the start of a tick so visuals can blend toward the result.
+pub fn snapshot_tick_positions(mut nodes: Query<&mut Node>) {
+    for mut node in nodes.iter_mut() {
+        // Bookkeeping only: must not mark paused nodes as changed every tick.
+        let node = node.bypass_change_detection();
+        node.tick_start_position = Some(node.position);
+    }
+}
+
+/// Advances the simulation clock by one tick while playing.
+pub fn advance_simulation_clock(playback: Res<PlaybackState>, time: Res<Time>, mut clock: ResMut<SimulationClock>) {
+    if !playback.is_playing() {
+        return;
+    }
+
+    clock.tick += 1;
+    clock.elapsed_secs += time.delta_secs();
+}
@@ -47,3 +47,144 @@ pub fn get_mouse_world_position(
     let (camera, camera_transform) = camera_query.single().ok()?;
     camera.viewport_to_world_2d(camera_transform, cursor_position).ok()
 }
+
+/// Smooth 2D value noise in `[-1, 1]`; the same `point` and `seed` always give the same value.
+pub fn value_noise(point: Vec2, seed: u64) -> f32 {
+    let cell = point.floor();
+    let frac = point - cell;
+    let (x, y) = (cell.x as i64, cell.y as i64);
+
+    let smooth = frac * frac * (Vec2::splat(3.0) - 2.0 * frac);
+    let bottom = lattice_value(x, y, seed).lerp(lattice_value(x + 1, y, seed), smooth.x);
+    let top = lattice_value(x, y + 1, seed).lerp(lattice_value(x + 1, y + 1, seed), smooth.x);
+    bottom.lerp(top, smooth.y)
+}
+
+/// Convex hull of `points`, counter-clockwise with no collinear vertices (Andrew's monotone chain).
+pub fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let cross = |o: Vec2, a: Vec2, b: Vec2| (a - o).perp_dot(b - o);
+    let mut hull: Vec<Vec2> = Vec::with_capacity(sorted.len() * 2);
+    for pass in [sorted.as_slice(), &sorted.iter().rev().copied().collect::<Vec<_>>()] {
+        let floor = hull.len();
+        for &point in pass {
+            while hull.len() >= floor + 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(point);
+        }
+        // The last point of each chain starts the next one.
+        hull.pop();
+    }
+    hull
+}
+
+/// Even-odd test for a closed outline of any winding; concave outlines are fine.
+pub fn point_in_polygon(points: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    for (i, &a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        if (a.y > point.y) != (b.y > point.y) && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Closest point to `point` on the edges of a closed outline.
+pub fn closest_point_on_polygon(points: &[Vec2], point: Vec2) -> Vec2 {
+    let mut closest = point;
+    let mut best = f32::MAX;
+    for (i, &a) in points.iter().enumerate() {
+        let edge = points[(i + 1) % points.len()] - a;
+        let t = ((point - a).dot(edge) / edge.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+        let candidate = a + edge * t;
+        let distance = candidate.distance_squared(point);
+        if distance < best {
+            best = distance;
+            closest = candidate;
+        }
+    }
+    closest
+}
+
+/// Fraction along `a0..a1` where it crosses `b0..b1`, if the segments intersect.
+pub fn segment_intersection(a0: Vec2, a1: Vec2, b0: Vec2, b1: Vec2) -> Option<f32> {
+    let r = a1 - a0;
+    let s = b1 - b0;
+    let denom = r.perp_dot(s);
+    if denom.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let offset = b0 - a0;
+    let t = offset.perp_dot(s) / denom;
+    let u = offset.perp_dot(r) / denom;
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then_some(t)
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+/// SplitMix64-style hash of a lattice corner, mapped to `[-1, 1)`.
+fn lattice_value(x: i64, y: i64, seed: u64) -> f32 {
+    let mut z = seed
+        ^ (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convex_hull_drops_collinear_interior_and_duplicate_points() {
+        let points = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 2.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 2.0),
+        ];
+        assert_eq!(
+            convex_hull(&points),
+            [Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0), Vec2::new(2.0, 2.0), Vec2::new(0.0, 2.0)]
+        );
+    }
+
+    #[test]
+    fn convex_hull_winds_counter_clockwise() {
+        let points = [Vec2::new(0.0, 3.0), Vec2::new(-2.0, -1.0), Vec2::new(4.0, 0.0), Vec2::new(1.0, 1.0)];
+        let hull = convex_hull(&points);
+        let twice_area: f32 = (0..hull.len()).map(|i| hull[i].perp_dot(hull[(i + 1) % hull.len()])).sum();
+        assert_eq!(hull.len(), 3);
+        assert!(twice_area > 0.0);
+    }
+
+    #[test]
+    fn convex_hull_of_a_line_keeps_only_its_ends() {
+        let points = [Vec2::new(3.0, 3.0), Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0), Vec2::new(1.0, 1.0)];
+        assert_eq!(convex_hull(&points), [Vec2::new(1.0, 1.0), Vec2::new(3.0, 3.0)]);
+    }
+
+    #[test]
+    fn convex_hull_of_repeated_points_is_a_single_point() {
+        assert_eq!(convex_hull(&[Vec2::ONE, Vec2::ONE, Vec2::ONE]), [Vec2::ONE]);
+        assert!(convex_hull(&[]).is_empty());
+    }
+}
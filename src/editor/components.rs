@@ -62,6 +62,42 @@ pub struct Selected;
 #[derive(Component, Clone, Debug, Default, Reflect)]
 pub struct Selectable;
 
+/// Marker for the rubber-band rectangle drawn while box selecting.
+#[derive(Component, Debug)]
+pub struct BoxSelectPreview;
+
+// =============================================================================
+// Pin Visual Components
+// =============================================================================
+
+/// Marker for the dashed tether drawn from a pinned node to its pin.
+#[derive(Component, Debug)]
+pub struct PinTether;
+
+/// Marker for the cross drawn at a pin's world point.
+#[derive(Component, Debug)]
+pub struct PinMarker;
+
+/// Links a pin visual back to its pinned node.
+#[derive(Component, Debug)]
+pub struct PinVisualOf(pub Entity);
+
+// =============================================================================
+// Obstacle Visual Components
+// =============================================================================
+
+/// Marker for an obstacle's filled body.
+#[derive(Component, Debug)]
+pub struct ObstacleFill;
+
+/// Marker for an obstacle's outline stroke.
+#[derive(Component, Debug)]
+pub struct ObstacleOutline;
+
+/// Links an obstacle visual back to its obstacle.
+#[derive(Component, Debug)]
+pub struct ObstacleVisualOf(pub Entity);
+
 // =============================================================================
 // Constraint Visual Components
 // =============================================================================
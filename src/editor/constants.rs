@@ -18,6 +18,7 @@ pub const ANCHOR_NODE_COLOR: Color = Color::srgb(1.0, 0.4, 0.4);
 pub const LEG_NODE_COLOR: Color = Color::srgb(0.4, 1.0, 0.4);
 pub const NORMAL_NODE_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
 pub const SELECTION_COLOR: Color = Color::srgb(0.3, 0.7, 1.0);
+pub const BOX_SELECT_COLOR: Color = Color::srgba(0.3, 0.7, 1.0, 0.6);
 
 // =============================================================================
 // Contact Point Constants
@@ -42,6 +43,9 @@ pub const LOOK_VECTOR_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
 
 pub const ANGLE_ARC_COLOR: Color = Color::srgba(1.0, 0.85, 0.3, 0.15);
 pub const ANGLE_ARC_SEGMENTS: usize = 32;
+pub const JOINT_LIMIT_COLOR: Color = Color::srgba(0.45, 0.8, 1.0, 0.7);
+/// Limb joint limit arcs are drawn at this multiple of the joint's radius.
+pub const JOINT_LIMIT_ARC_SCALE: f32 = 1.6;
 
 // =============================================================================
 // Eye Constants
@@ -60,6 +64,7 @@ pub const EYE_DISTANCE_RATIO: f32 = 0.5;
 pub const PLAYGROUND_FILL_COLOR: Color = Color::srgba(0.10, 0.11, 0.12, 0.4);
 pub const PLAYGROUND_OUTSIDE_COLOR: Color = Color::srgba(0.06, 0.065, 0.07, 0.8);
 pub const PLAYGROUND_BORDER_COLOR: Color = Color::srgb(0.4, 0.45, 0.5);
+pub const PLAYGROUND_CIRCLE_SEGMENTS: usize = 96;
 
 // =============================================================================
 // Constraint Color Constants
@@ -129,3 +134,50 @@ pub const ZOOM_MAX: f32 = 2.0;
 pub const DEFAULT_ZOOM: f32 = 1.25;
 pub const ZOOM_SPEED: f32 = 0.1;
 pub const CAMERA_LERP_FACTOR: f32 = 0.1;
+/// Distance beyond the screen edge a creature must reach before its visuals stop syncing.
+pub const CULL_MARGIN: f32 = 400.0;
+
+// =============================================================================
+// History Constants
+// =============================================================================
+
+pub const MAX_HISTORY_ENTRIES: usize = 100;
+
+// =============================================================================
+// Selection Constants
+// =============================================================================
+
+pub const BOX_SELECT_THICKNESS: f32 = 1.5;
+pub const BOX_SELECT_MIN_SIZE: f32 = 2.0;
+pub const PASTE_FALLBACK_OFFSET: Vec2 = Vec2::new(30.0, -30.0);
+
+// =============================================================================
+// Pin Constants
+// =============================================================================
+
+pub const PIN_COLOR: Color = Color::srgba(1.0, 0.75, 0.3, 0.9);
+pub const PIN_MARKER_SIZE: f32 = 10.0;
+pub const PIN_MARKER_THICKNESS: f32 = 2.0;
+pub const PIN_HANDLE_RADIUS: f32 = 10.0;
+
+// =============================================================================
+// Force Field Constants
+// =============================================================================
+
+pub const FIELD_COLOR: Color = Color::srgba(0.55, 0.9, 0.75, 0.6);
+pub const FIELD_ARROW_COLOR: Color = Color::srgba(0.55, 0.9, 0.75, 0.9);
+pub const FIELD_HANDLE_RADIUS: f32 = 12.0;
+/// Sample arrows per side of the grid drawn inside each field.
+pub const FIELD_GIZMO_SAMPLES: usize = 5;
+
+// =============================================================================
+// Obstacle Constants
+// =============================================================================
+
+pub const OBSTACLE_FILL_COLOR: Color = Color::srgb(0.22, 0.24, 0.27);
+pub const OBSTACLE_OUTLINE_COLOR: Color = Color::srgb(0.4, 0.45, 0.5);
+pub const OBSTACLE_PREVIEW_COLOR: Color = Color::srgba(0.4, 0.45, 0.5, 0.8);
+pub const OBSTACLE_OUTLINE_THICKNESS: f32 = 2.0;
+pub const OBSTACLE_CIRCLE_SEGMENTS: usize = 48;
+/// Drags shorter than this are treated as stray clicks rather than new obstacles.
+pub const OBSTACLE_MIN_SIZE: f32 = 4.0;
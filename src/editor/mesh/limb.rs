@@ -7,6 +7,7 @@ use crate::core::components::{LimbSet, Node};
 use crate::core::resources::ConstraintGraph;
 use crate::editor::components::{LimbMesh, LimbOutline, SkinGroupIndex};
 use crate::editor::constants::*;
+use crate::editor::resources::OffscreenCulling;
 use crate::editor::mesh::skin::{
     build_outline_mesh, build_strip_fill_mesh, evaluate_catmull_rom_closed,
     evaluate_catmull_rom_open,
@@ -53,6 +54,8 @@ pub fn sync_limb_visual(
     display_settings: Res<DisplaySettings>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    fixed_time: Res<Time<Fixed>>,
+    culling: Res<OffscreenCulling>,
     graph: Res<ConstraintGraph>,
     limb_sets: Query<(Entity, &LimbSet)>,
     nodes: Query<&Node>,
@@ -67,17 +70,22 @@ pub fn sync_limb_visual(
 ) {
     let show = display_settings.show_skin;
     let opaque = !display_settings.show_nodes;
+    let alpha = fixed_time.overstep_fraction();
 
     let mut group_fills: HashMap<u32, Vec<Mesh>> = HashMap::new();
     let mut group_polygons: HashMap<u32, Vec<Vec<Vec2>>> = HashMap::new();
 
     if show {
         for (body_entity, limb_set) in limb_sets.iter() {
+            if culling.is_culled(body_entity) {
+                continue;
+            }
+
             let body_node = match nodes.get(body_entity) {
                 Ok(n) => n,
                 Err(_) => continue,
             };
-            let body_pos = body_node.position;
+            let body_pos = body_node.interpolated_position(alpha);
             let body_radius = body_node.radius;
             let group_id = graph.get_group(body_entity).unwrap_or(0);
 
@@ -86,7 +94,7 @@ pub fn sync_limb_visual(
                 let mut radii: Vec<f32> = vec![body_radius];
                 for &joint_entity in &limb.joints {
                     if let Ok(joint_node) = nodes.get(joint_entity) {
-                        positions.push(joint_node.position);
+                        positions.push(joint_node.interpolated_position(alpha));
                         radii.push(joint_node.radius);
                     }
                 }
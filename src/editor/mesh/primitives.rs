@@ -255,3 +255,70 @@ pub fn create_arc_mesh(radius: f32, start_angle: f32, end_angle: f32, segments:
         .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
         .with_inserted_indices(Indices::U32(indices))
 }
+
+/// Creates a filled convex polygon mesh via fan triangulation from its first vertex.
+pub fn create_convex_polygon_mesh(points: &[Vec2]) -> Mesh {
+    let positions: Vec<[f32; 3]> = points.iter().map(|p| [p.x, p.y, 0.0]).collect();
+    let mut indices = Vec::with_capacity(points.len().saturating_sub(2) * 3);
+
+    for i in 1..points.len().saturating_sub(1) {
+        indices.push(0);
+        indices.push(i as u32);
+        indices.push((i + 1) as u32);
+    }
+
+    Mesh::new(PrimitiveTopology::TriangleList, default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+/// Creates a filled mesh for any simple polygon, concave or not, by ear clipping.
+pub fn create_polygon_mesh(points: &[Vec2]) -> Mesh {
+    let positions: Vec<[f32; 3]> = points.iter().map(|p| [p.x, p.y, 0.0]).collect();
+    let indices = triangulate_polygon(points);
+
+    Mesh::new(PrimitiveTopology::TriangleList, default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+fn triangulate_polygon(points: &[Vec2]) -> Vec<u32> {
+    let signed_area: f32 = (0..points.len()).map(|i| points[i].perp_dot(points[(i + 1) % points.len()])).sum();
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    if signed_area < 0.0 {
+        remaining.reverse();
+    }
+
+    let mut indices = Vec::with_capacity(points.len().saturating_sub(2) * 3);
+    let mut guard = 0;
+    while remaining.len() > 3 && guard < points.len() * points.len() {
+        guard += 1;
+        let n = remaining.len();
+        let ear = (0..n).find(|&i| {
+            let (a, b, c) = (points[remaining[(i + n - 1) % n]], points[remaining[i]], points[remaining[(i + 1) % n]]);
+            (b - a).perp_dot(c - b) > 0.0
+                && remaining
+                    .iter()
+                    .map(|&j| points[j])
+                    .filter(|p| *p != a && *p != b && *p != c)
+                    .all(|p| !point_in_triangle(p, a, b, c))
+        });
+        // Degenerate outline: fall back to a fan for what is left.
+        let Some(i) = ear else { break };
+
+        indices.extend([remaining[(i + n - 1) % n], remaining[i], remaining[(i + 1) % n]].map(|v| v as u32));
+        remaining.remove(i);
+    }
+    for i in 1..remaining.len().saturating_sub(1) {
+        indices.extend([remaining[0], remaining[i], remaining[i + 1]].map(|v| v as u32));
+    }
+    indices
+}
+
+fn point_in_triangle(p: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    (b - a).perp_dot(p - a) >= 0.0 && (c - b).perp_dot(p - b) >= 0.0 && (a - c).perp_dot(p - c) >= 0.0
+}
@@ -201,6 +201,29 @@ pub fn build_outline_mesh(polygons: &[Vec<Vec2>], thickness: f32) -> Mesh {
         .with_inserted_indices(Indices::U32(indices))
 }
 
+/// Appends a copy of `mesh` shifted by each of `offsets`, for drawing across a wrapping seam.
+pub fn append_mesh_copies(mesh: &mut Mesh, offsets: &[Vec2]) {
+    if offsets.is_empty() {
+        return;
+    }
+
+    let Some(positions) = mesh.attribute(Mesh::ATTRIBUTE_POSITION).and_then(|p| p.as_float3()).map(<[_]>::to_vec) else {
+        return;
+    };
+    let indices: Vec<u32> = mesh.indices().map(|i| i.iter().map(|i| i as u32).collect()).unwrap_or_default();
+
+    let mut all_positions = positions.clone();
+    let mut all_indices = indices.clone();
+    for offset in offsets {
+        let base = all_positions.len() as u32;
+        all_positions.extend(positions.iter().map(|p| [p[0] + offset.x, p[1] + offset.y, p[2]]));
+        all_indices.extend(indices.iter().map(|i| base + i));
+    }
+
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, all_positions);
+    mesh.insert_indices(Indices::U32(all_indices));
+}
+
 // =============================================================================
 // Private Methods
 // =============================================================================
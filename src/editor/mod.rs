@@ -7,38 +7,49 @@ pub mod tools;
 pub mod visuals;
 pub mod mesh;
 
-pub use resources::SkinChains;
+pub use resources::{EditorClipboard, EditorHistory, HistoryStep, OffscreenCulling, SkinChains};
 
 use bevy::prelude::*;
 
-use crate::core::{
-    ConstraintGraph, Playground, anchor_movement_system, collision_avoidance_system, constraint_solving_system,
-    fabrik_solving_system, limb_builder_system, update_constraint_graph, verlet_integration_system,
-};
 use tools::*;
 use visuals::*;
 use mesh::*;
 
 /// Plugin for editor visualization and interaction systems.
+///
+/// Expects [`crate::core::SimulationPlugin`] to provide the simulation resources.
 pub struct EditorPlugin;
 
 impl Plugin for EditorPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Selection>();
-        app.init_resource::<ConstraintGraph>();
         app.init_resource::<SkinChains>();
-        app.init_resource::<Playground>();
+        app.init_resource::<OffscreenCulling>();
+        app.init_resource::<EditorHistory>();
+        app.init_resource::<EditorClipboard>();
         app.init_resource::<EdgeCreationState>();
+        app.init_resource::<BoxSelectState>();
+        app.init_resource::<HandleDragState>();
+        app.init_resource::<OutlineDrawState>();
         app.init_resource::<CameraState>();
 
+        app.add_systems(Last, commit_history);
+        app.add_systems(Update, update_offscreen_culling.after(handle_camera_follow));
         app.add_systems(Startup, (spawn_playground_visual, spawn_skin_visual, spawn_limb_visual));
         app.add_systems(
             PostUpdate,
             (
                 spawn_node_visuals,
+                interpolate_node_transforms,
                 sync_node_visuals,
                 spawn_constraint_visuals,
                 sync_constraint_visuals,
+                spawn_pin_visuals,
+                sync_pin_visuals,
+                draw_force_field_gizmos,
+                draw_joint_limit_gizmos,
+                spawn_obstacle_visuals,
+                sync_obstacle_visuals,
                 sync_playground_visual,
                 update_skin_chains,
                 sync_skin_visual,
@@ -56,7 +67,12 @@ impl Plugin for EditorPlugin {
                 handle_camera_pan,
                 handle_follow_toggle,
                 handle_camera_follow,
+                handle_pin_drag,
+                handle_force_field_drag,
+                handle_obstacle_drag,
                 handle_node_selection,
+                handle_box_select,
+                handle_select_connected,
             )
                 .chain(),
         );
@@ -64,28 +80,21 @@ impl Plugin for EditorPlugin {
             Update,
             (
                 handle_delete_selected,
+                handle_clipboard_shortcuts,
                 handle_add_node_tool,
+                handle_prefab_stamp,
+                handle_obstacle_tool,
+                handle_arena_outline_tool,
+                render_outline_preview,
                 handle_add_edge_tool,
                 cancel_edge_creation,
                 render_constraint_preview,
-            )
-                .chain()
-                .after(handle_node_selection),
-        );
-        app.add_systems(
-            Update,
-            (
-                update_constraint_graph,
-                limb_builder_system,
-                anchor_movement_system,
-                verlet_integration_system,
-                constraint_solving_system,
-                fabrik_solving_system,
-                collision_avoidance_system,
+                render_box_select_preview,
+                sync_selected_markers,
                 update_selection_visuals,
             )
                 .chain()
-                .after(render_constraint_preview),
+                .after(handle_node_selection),
         );
     }
 }
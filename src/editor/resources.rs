@@ -1,6 +1,315 @@
 use bevy::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+use crate::core::serialization::{ConstraintData, LimbSetData, PinData};
+use crate::core::components::{BoundaryMode, PlaygroundShape};
+use crate::core::{ForceField, Node, Obstacle, SceneData};
+use crate::editor::constants::MAX_HISTORY_ENTRIES;
 
 #[derive(Resource, Default)]
 pub struct SkinChains {
     pub chains: Vec<Vec<(Entity, f32)>>,
 }
+
+/// Nodes of creatures far enough off-screen that visual sync leaves them alone.
+#[derive(Resource, Default)]
+pub struct OffscreenCulling {
+    culled: HashSet<Entity>,
+    /// Culled last frame but back in view, so change-driven syncs have to catch up.
+    revealed: HashSet<Entity>,
+}
+
+impl OffscreenCulling {
+    pub fn is_culled(&self, entity: Entity) -> bool {
+        self.culled.contains(&entity)
+    }
+
+    pub fn is_revealed(&self, entity: Entity) -> bool {
+        self.revealed.contains(&entity)
+    }
+
+    pub fn update(&mut self, culled: HashSet<Entity>) {
+        self.revealed = self.culled.difference(&culled).copied().collect();
+        self.culled = culled;
+    }
+}
+
+/// Nodes, constraints and limb sets captured by Ctrl + C.
+#[derive(Resource, Default)]
+pub struct EditorClipboard {
+    pub scene: Option<SceneData>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistoryStep {
+    Undo,
+    Redo,
+}
+
+/// Undo/redo history, kept as diffs between consecutive scene states.
+///
+/// Editing code calls [`EditorHistory::mark_edited`]; the edit is committed at the end of
+/// the frame once the mouse is released, so a slider drag becomes a single entry. Only the
+/// latest scene is held in full, as the baseline the next edit is diffed against.
+#[derive(Resource, Default)]
+pub struct EditorHistory {
+    undo: VecDeque<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+    /// Scene as of the last commit; `None` until first captured or after playback moved things.
+    pub baseline: Option<SceneData>,
+    pub pending_edit: bool,
+    pub requested: Option<HistoryStep>,
+    /// Scene to respawn, set by undo/redo and consumed in Update.
+    pub restore: Option<SceneData>,
+}
+
+impl EditorHistory {
+    pub fn mark_edited(&mut self) {
+        self.pending_edit = true;
+    }
+
+    pub fn request(&mut self, step: HistoryStep) {
+        self.requested = Some(step);
+    }
+
+    /// Forgets every entry and the baseline, as playback leaves the scene the diffs were taken against.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.baseline = None;
+        self.pending_edit = false;
+        self.requested = None;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Records the change from the baseline to `current` and makes `current` the new baseline.
+    ///
+    /// With no baseline yet, `current` is only captured.
+    pub fn commit(&mut self, current: SceneData) {
+        self.pending_edit = false;
+        let Some(previous) = self.baseline.take() else {
+            self.baseline = Some(current);
+            return;
+        };
+
+        let entry = HistoryEntry {
+            undo: SceneDiff::between(&current, &previous),
+            redo: SceneDiff::between(&previous, &current),
+        };
+        self.baseline = Some(current);
+        if entry.redo.is_empty() {
+            return;
+        }
+        if self.undo.len() == MAX_HISTORY_ENTRIES {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(entry);
+        self.redo.clear();
+    }
+
+    /// Steps back or forward from the baseline, queueing the resulting scene for restore.
+    pub fn apply_step(&mut self, step: HistoryStep) {
+        let Some(baseline) = &self.baseline else {
+            return;
+        };
+        let entry = match step {
+            HistoryStep::Undo => self.undo.pop_back(),
+            HistoryStep::Redo => self.redo.pop(),
+        };
+        let Some(entry) = entry else { return };
+
+        let mut target = baseline.clone();
+        match step {
+            HistoryStep::Undo => {
+                entry.undo.apply(&mut target);
+                self.redo.push(entry);
+            }
+            HistoryStep::Redo => {
+                entry.redo.apply(&mut target);
+                self.undo.push_back(entry);
+            }
+        }
+        self.baseline = Some(target.clone());
+        self.restore = Some(target);
+    }
+}
+
+/// One committed edit, as the diffs that step across it in either direction.
+struct HistoryEntry {
+    undo: SceneDiff,
+    redo: SceneDiff,
+}
+
+/// Changes that turn one scene into another, list by list.
+struct SceneDiff {
+    nodes: ListDiff<Node>,
+    constraints: ListDiff<ConstraintData>,
+    limb_sets: ListDiff<LimbSetData>,
+    pins: ListDiff<PinData>,
+    force_fields: ListDiff<ForceField>,
+    obstacles: ListDiff<Obstacle>,
+    seed: Option<u64>,
+    shape: Option<PlaygroundShape>,
+    boundary_mode: Option<BoundaryMode>,
+}
+
+impl SceneDiff {
+    fn between(from: &SceneData, to: &SceneData) -> Self {
+        Self {
+            nodes: ListDiff::between(&from.nodes, &to.nodes),
+            constraints: ListDiff::between(&from.constraints, &to.constraints),
+            limb_sets: ListDiff::between(&from.limb_sets, &to.limb_sets),
+            pins: ListDiff::between(&from.pins, &to.pins),
+            force_fields: ListDiff::between(&from.force_fields, &to.force_fields),
+            obstacles: ListDiff::between(&from.obstacles, &to.obstacles),
+            seed: (from.seed != to.seed).then_some(to.seed),
+            shape: (from.shape != to.shape).then(|| to.shape.clone()),
+            boundary_mode: (from.boundary_mode != to.boundary_mode).then_some(to.boundary_mode),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+            && self.constraints.is_empty()
+            && self.limb_sets.is_empty()
+            && self.pins.is_empty()
+            && self.force_fields.is_empty()
+            && self.obstacles.is_empty()
+            && self.seed.is_none()
+            && self.shape.is_none()
+            && self.boundary_mode.is_none()
+    }
+
+    fn apply(&self, scene: &mut SceneData) {
+        self.nodes.apply(&mut scene.nodes);
+        self.constraints.apply(&mut scene.constraints);
+        self.limb_sets.apply(&mut scene.limb_sets);
+        self.pins.apply(&mut scene.pins);
+        self.force_fields.apply(&mut scene.force_fields);
+        self.obstacles.apply(&mut scene.obstacles);
+        if let Some(seed) = self.seed {
+            scene.seed = seed;
+        }
+        if let Some(shape) = &self.shape {
+            scene.shape = shape.clone();
+        }
+        if let Some(boundary_mode) = self.boundary_mode {
+            scene.boundary_mode = boundary_mode;
+        }
+    }
+}
+
+/// The target length of a list plus every item that differs from the source at its index.
+struct ListDiff<T> {
+    len: usize,
+    resized: bool,
+    changed: Vec<(usize, T)>,
+}
+
+impl<T: Clone + PartialEq> ListDiff<T> {
+    fn between(from: &[T], to: &[T]) -> Self {
+        let changed = to
+            .iter()
+            .enumerate()
+            .filter(|&(i, item)| from.get(i) != Some(item))
+            .map(|(i, item)| (i, item.clone()))
+            .collect();
+        Self {
+            len: to.len(),
+            resized: from.len() != to.len(),
+            changed,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.resized && self.changed.is_empty()
+    }
+
+    /// Items past the source's end are all in `changed`, in order, so they append cleanly.
+    fn apply(&self, list: &mut Vec<T>) {
+        list.truncate(self.len);
+        for (i, item) in &self.changed {
+            match list.get_mut(*i) {
+                Some(slot) => *slot = item.clone(),
+                None => list.push(item.clone()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scene(xs: &[f32]) -> SceneData {
+        SceneData {
+            nodes: xs.iter().map(|&x| Node { position: Vec2::new(x, 0.0), ..default() }).collect(),
+            constraints: Vec::new(),
+            limb_sets: Vec::new(),
+            pins: Vec::new(),
+            force_fields: Vec::new(),
+            obstacles: Vec::new(),
+            seed: 0,
+            shape: PlaygroundShape::Rectangle,
+            boundary_mode: BoundaryMode::Bounce,
+        }
+    }
+
+    fn xs(scene: &SceneData) -> Vec<f32> {
+        scene.nodes.iter().map(|n| n.position.x).collect()
+    }
+
+    #[test]
+    fn list_diff_replays_changes_growth_and_shrink() {
+        let cases: [(&[i32], &[i32]); 4] = [(&[1, 2, 3], &[1, 5, 3]), (&[1, 2], &[1, 2, 3, 4]), (&[1, 2, 3, 4], &[9, 2]), (&[], &[7])];
+        for (from, to) in cases {
+            let mut list = from.to_vec();
+            ListDiff::between(from, to).apply(&mut list);
+            assert_eq!(list, to);
+        }
+    }
+
+    #[test]
+    fn list_diff_of_equal_lists_is_empty() {
+        assert!(ListDiff::between(&[1, 2, 3], &[1, 2, 3]).is_empty());
+        assert!(!ListDiff::between(&[1, 2, 3], &[1, 2]).is_empty());
+    }
+
+    #[test]
+    fn undo_and_redo_step_between_commits() {
+        let mut history = EditorHistory::default();
+        history.commit(scene(&[0.0]));
+        history.commit(scene(&[0.0, 1.0]));
+        history.commit(scene(&[2.0, 1.0]));
+
+        history.apply_step(HistoryStep::Undo);
+        assert_eq!(xs(&history.restore.take().unwrap()), [0.0, 1.0]);
+        history.apply_step(HistoryStep::Undo);
+        assert_eq!(xs(&history.restore.take().unwrap()), [0.0]);
+        history.apply_step(HistoryStep::Redo);
+        assert_eq!(xs(&history.restore.take().unwrap()), [0.0, 1.0]);
+    }
+
+    #[test]
+    fn undo_after_play_and_pause_leaves_the_simulated_scene_alone() {
+        let mut history = EditorHistory::default();
+        history.commit(scene(&[0.0, 1.0]));
+        history.commit(scene(&[5.0, 1.0]));
+
+        // Play drops the history; pausing captures the drifted scene as the new baseline.
+        history.clear();
+        history.commit(scene(&[8.0, 3.0, 4.0]));
+
+        assert!(!history.can_undo() && !history.can_redo());
+        history.apply_step(HistoryStep::Undo);
+        assert!(history.restore.is_none());
+        assert_eq!(xs(history.baseline.as_ref().unwrap()), [8.0, 3.0, 4.0]);
+    }
+}
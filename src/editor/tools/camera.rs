@@ -3,7 +3,7 @@
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
 
-use crate::core::Playground;
+use crate::core::{BoundaryMode, Playground};
 use crate::editor::constants::{CAMERA_LERP_FACTOR, DEFAULT_ZOOM, ZOOM_MAX, ZOOM_MIN, ZOOM_SPEED};
 use crate::ui::state::{EditorTool, EditorToolState, InputState};
 
@@ -188,7 +188,11 @@ fn apply_pan_movement(camera_transform: &mut Transform, delta: Vec2, scale: f32,
     let world_delta = Vec3::new(-delta.x * scale, delta.y * scale, 0.0);
     camera_transform.translation += world_delta;
 
-    let bounds = playground.half_size;
+    if playground.boundary_mode == BoundaryMode::Open {
+        return;
+    }
+
+    let bounds = playground.half_size();
     camera_transform.translation.x = camera_transform.translation.x.clamp(-bounds.x, bounds.x);
     camera_transform.translation.y = camera_transform.translation.y.clamp(-bounds.y, bounds.y);
 }
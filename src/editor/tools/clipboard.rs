@@ -0,0 +1,83 @@
+//! Copy, paste and duplicate of selected node subgraphs.
+
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+
+use super::input::cursor_world_pos;
+use super::selection::Selection;
+use crate::core::components::LimbSet;
+use crate::core::{DistanceConstraint, Node as SimNode, Pin, SceneData, build_subgraph_data, spawn_scene_fragment};
+use crate::editor::constants::*;
+use crate::editor::resources::{EditorClipboard, EditorHistory};
+
+/// Ctrl + C copies the selection, Ctrl + V pastes it at the cursor, Ctrl + D does both at once,
+/// unless typing in UI.
+pub fn handle_clipboard_shortcuts(
+    mut contexts: EguiContexts,
+    mut commands: Commands,
+    mut selection: ResMut<Selection>,
+    mut clipboard: ResMut<EditorClipboard>,
+    mut history: ResMut<EditorHistory>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    node_query: Query<(Entity, &mut SimNode)>,
+    constraint_query: Query<(Entity, &DistanceConstraint)>,
+    limb_set_query: Query<(Entity, &mut LimbSet)>,
+    pin_query: Query<(Entity, &Pin)>,
+) {
+    if let Ok(ctx) = contexts.ctx_mut() {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+    }
+
+    if !keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+
+    let copy = keyboard.just_pressed(KeyCode::KeyC);
+    let duplicate = keyboard.just_pressed(KeyCode::KeyD);
+    let paste = keyboard.just_pressed(KeyCode::KeyV);
+
+    if (copy || duplicate) && !selection.is_empty() {
+        let fragment = build_subgraph_data(&node_query, &constraint_query, &limb_set_query, &pin_query, &selection.entities);
+        if duplicate {
+            paste_fragment(&mut commands, &mut selection, &fragment, cursor_world_pos(&windows, &cameras));
+            history.mark_edited();
+        } else {
+            clipboard.scene = Some(fragment);
+        }
+    }
+
+    if paste {
+        if let Some(fragment) = &clipboard.scene {
+            paste_fragment(&mut commands, &mut selection, fragment, cursor_world_pos(&windows, &cameras));
+            history.mark_edited();
+        }
+    }
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+/// Spawns a copy of `fragment` centered on `cursor` (or nudged aside) and selects it.
+fn paste_fragment(commands: &mut Commands, selection: &mut Selection, fragment: &SceneData, cursor: Option<Vec2>) {
+    if fragment.nodes.is_empty() {
+        return;
+    }
+
+    let mut fragment = fragment.clone();
+    let offset = match cursor {
+        Some(cursor) => cursor - fragment.centroid(),
+        None => PASTE_FALLBACK_OFFSET,
+    };
+    fragment.translate(offset);
+
+    let entities = spawn_scene_fragment(commands, &fragment);
+    selection.deselect();
+    for entity in entities {
+        selection.add(entity);
+    }
+}
@@ -7,6 +7,7 @@ use crate::core::{DistanceConstraint, Node as SimNode, NodeType};
 use crate::editor::components::{ConstraintPreview, Selectable};
 use crate::editor::constants::*;
 use crate::editor::mesh::primitives::create_dashed_line_mesh;
+use crate::editor::resources::EditorHistory;
 use crate::ui::state::{EditorTool, EditorToolState, InputState};
 
 /// Tracks the in-progress edge creation state.
@@ -18,6 +19,7 @@ pub struct EdgeCreationState {
 /// Handles left-clicks to create distance constraints between nodes.
 pub fn handle_add_edge_tool(
     mut commands: Commands,
+    mut history: ResMut<EditorHistory>,
     tool_state: Res<EditorToolState>,
     input_state: Res<InputState>,
     mouse_button: Res<ButtonInput<MouseButton>>,
@@ -76,6 +78,7 @@ pub fn handle_add_edge_tool(
                 Name::new("Distance Constraint"),
                 DistanceConstraint::new(first, clicked_entity, node_a.position.distance(node_b.position)),
             ));
+            history.mark_edited();
         }
     }
 }
@@ -0,0 +1,43 @@
+//! Dragging force fields around the viewport.
+
+use bevy::prelude::*;
+
+use super::input::{DragHandle, HandleDragState, cursor_world_pos, pick_handle_at};
+use crate::core::ForceField;
+use crate::editor::constants::*;
+use crate::editor::resources::EditorHistory;
+use crate::ui::state::{EditorTool, EditorToolState, InputState};
+
+/// Grabs a force field by its center handle and moves it until the button is released.
+pub fn handle_force_field_drag(
+    mut drag: ResMut<HandleDragState>,
+    mut history: ResMut<EditorHistory>,
+    tool_state: Res<EditorToolState>,
+    input_state: Res<InputState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut fields: Query<(Entity, &mut ForceField)>,
+) {
+    if tool_state.active != EditorTool::Cursor || !drag.update_release(&mouse_button) {
+        return;
+    }
+    let Some(world_pos) = cursor_world_pos(&windows, &cameras) else {
+        return;
+    };
+
+    if mouse_button.just_pressed(MouseButton::Left) && drag.target.is_none() && input_state.can_interact_with_world() {
+        let handles = fields.iter().map(|(entity, field)| (entity, field.position));
+        drag.target = pick_handle_at(world_pos, FIELD_HANDLE_RADIUS, handles).map(DragHandle::ForceField);
+    }
+
+    let Some(DragHandle::ForceField(entity)) = drag.target else {
+        return;
+    };
+    if let Ok((_, mut field)) = fields.get_mut(entity) {
+        if field.position != world_pos {
+            field.position = world_pos;
+            history.mark_edited();
+        }
+    }
+}
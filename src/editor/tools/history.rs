@@ -0,0 +1,46 @@
+//! Undo/redo bookkeeping for editor operations.
+
+use bevy::prelude::*;
+
+use crate::core::components::LimbSet;
+use crate::core::{DistanceConstraint, ForceField, Node as SimNode, Obstacle, Pin, PlaybackState, Playground, SimulationRng, build_scene_data};
+use crate::editor::resources::EditorHistory;
+
+/// Commits pending edits and services undo/redo requests once the frame's commands have landed.
+///
+/// Nothing is recorded while playing; the history is cleared instead so the simulated
+/// motion is never mistaken for an edit.
+pub fn commit_history(
+    mut history: ResMut<EditorHistory>,
+    playback: Res<PlaybackState>,
+    rng: Res<SimulationRng>,
+    playground: Res<Playground>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    node_query: Query<(Entity, &mut SimNode)>,
+    constraint_query: Query<(Entity, &DistanceConstraint)>,
+    limb_set_query: Query<(Entity, &mut LimbSet)>,
+    pin_query: Query<(Entity, &Pin)>,
+    field_query: Query<(Entity, &mut ForceField)>,
+    obstacle_query: Query<&Obstacle>,
+) {
+    if playback.is_playing() {
+        history.clear();
+        return;
+    }
+
+    // A restore is still in flight; its scene is already the baseline.
+    if history.restore.is_some() {
+        return;
+    }
+
+    // Only build the scene when there is something to record; stepping works off the baseline.
+    let dragging = mouse_button.pressed(MouseButton::Left);
+    let commit_due = history.pending_edit && !dragging;
+    if history.baseline.is_none() || commit_due {
+        let current = build_scene_data(&node_query, &constraint_query, &limb_set_query, &pin_query, &field_query, &obstacle_query, &playground, rng.seed);
+        history.commit(current);
+    }
+    if let Some(step) = history.requested.take() {
+        history.apply_step(step);
+    }
+}
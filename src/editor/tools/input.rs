@@ -4,6 +4,7 @@ use bevy::prelude::*;
 
 use crate::core::Node as SimNode;
 use crate::editor::components::Selectable;
+use crate::ui::state::EditorTool;
 
 pub fn cursor_world_pos(windows: &Query<&Window>, cameras: &Query<(&Camera, &GlobalTransform)>) -> Option<Vec2> {
     let cursor_pos = cursor_screen_pos(windows)?;
@@ -45,3 +46,78 @@ pub fn pick_all_nodes_at(
     hits.sort();
     hits
 }
+
+/// In-progress outline shared by the obstacle and arena drawing tools.
+#[derive(Resource, Default)]
+pub struct OutlineDrawState {
+    /// Where a circle or box drag started.
+    pub drag_start: Option<Vec2>,
+    /// Corners placed so far for a polygon.
+    pub points: Vec<Vec2>,
+    /// The tool these points belong to.
+    pub owner: Option<EditorTool>,
+}
+
+impl OutlineDrawState {
+    pub fn is_drawing(&self) -> bool {
+        self.drag_start.is_some() || !self.points.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.drag_start = None;
+        self.points.clear();
+    }
+
+    /// Hands the outline to `tool`, dropping anything another tool left behind.
+    pub fn claim(&mut self, tool: EditorTool) {
+        if self.owner != Some(tool) {
+            self.clear();
+            self.owner = Some(tool);
+        }
+    }
+
+    /// Drops the outline if `tool` owns it; call while that tool is inactive.
+    pub fn release(&mut self, tool: EditorTool) {
+        if self.owner == Some(tool) {
+            self.clear();
+            self.owner = None;
+        }
+    }
+}
+
+/// A world-space handle that can be grabbed with the cursor tool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DragHandle {
+    /// The pin on this node.
+    Pin(Entity),
+    /// The center of this force field.
+    ForceField(Entity),
+    /// Anywhere inside this obstacle.
+    Obstacle(Entity),
+}
+
+/// The handle currently held by the cursor, shared so only one tool grabs a click.
+#[derive(Resource, Default)]
+pub struct HandleDragState {
+    pub target: Option<DragHandle>,
+}
+
+impl HandleDragState {
+    /// Clears the grab once the button is up; returns whether a drag may continue this frame.
+    pub fn update_release(&mut self, mouse_button: &ButtonInput<MouseButton>) -> bool {
+        if !mouse_button.pressed(MouseButton::Left) {
+            self.target = None;
+            return false;
+        }
+        true
+    }
+}
+
+/// The closest of `handles` within `radius` of `world_pos`.
+pub fn pick_handle_at(world_pos: Vec2, radius: f32, handles: impl Iterator<Item = (Entity, Vec2)>) -> Option<Entity> {
+    handles
+        .map(|(entity, point)| (entity, point.distance(world_pos)))
+        .filter(|(_, dist)| *dist <= radius)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
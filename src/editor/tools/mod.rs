@@ -1,13 +1,27 @@
 //! Editor interaction tools and input helpers.
 
 pub mod camera;
+pub mod clipboard;
 pub mod constraint;
+pub mod force_field;
+pub mod history;
 pub mod input;
 pub mod node;
+pub mod obstacle;
+pub mod pin;
+pub mod playground;
+pub mod prefab;
 pub mod selection;
 
 pub use camera::*;
+pub use clipboard::*;
 pub use constraint::*;
+pub use force_field::*;
+pub use history::*;
 pub use input::*;
 pub use node::*;
+pub use obstacle::*;
+pub use pin::*;
+pub use playground::*;
+pub use prefab::*;
 pub use selection::*;
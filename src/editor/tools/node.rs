@@ -5,12 +5,14 @@ use bevy::prelude::*;
 use super::input::cursor_world_pos;
 use crate::core::{Node as SimNode, NodeType};
 use crate::editor::constants::*;
+use crate::editor::resources::EditorHistory;
 use crate::ui::state::InputState;
 use crate::ui::state::{EditorTool, EditorToolState};
 
 /// Handles left-clicks to add new nodes to the simulation.
 pub fn handle_add_node_tool(
     mut commands: Commands,
+    mut history: ResMut<EditorHistory>,
     tool_state: Res<EditorToolState>,
     input_state: Res<InputState>,
     mouse_button: Res<ButtonInput<MouseButton>>,
@@ -34,4 +36,5 @@ pub fn handle_add_node_tool(
             .with_radius(DEFAULT_NODE_RADIUS)
             .with_node_type(NodeType::Normal),
     ));
+    history.mark_edited();
 }
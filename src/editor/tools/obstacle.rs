@@ -0,0 +1,184 @@
+//! Drawing, moving and removing static obstacles.
+
+use bevy::prelude::*;
+
+use super::input::{DragHandle, HandleDragState, OutlineDrawState, cursor_world_pos, pick_node_at};
+use crate::core::{Node as SimNode, Obstacle, ObstacleShapeKind};
+use crate::editor::components::Selectable;
+use crate::editor::constants::*;
+use crate::editor::resources::EditorHistory;
+use crate::ui::state::{EditorTool, EditorToolState, InputState};
+
+/// Draws circles and boxes by dragging and polygons by clicking corners; right-click removes an obstacle.
+pub fn handle_obstacle_tool(
+    mut commands: Commands,
+    mut tool_state: ResMut<EditorToolState>,
+    mut draw: ResMut<OutlineDrawState>,
+    mut history: ResMut<EditorHistory>,
+    input_state: Res<InputState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    obstacles: Query<(Entity, &Obstacle)>,
+) {
+    if tool_state.active != EditorTool::Obstacle {
+        draw.release(EditorTool::Obstacle);
+        return;
+    }
+    draw.claim(EditorTool::Obstacle);
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        if draw.is_drawing() {
+            draw.clear();
+        } else {
+            tool_state.active = EditorTool::Cursor;
+        }
+        return;
+    }
+
+    let shape = tool_state.obstacle_shape;
+    let close_polygon = keyboard.just_pressed(KeyCode::Enter)
+        || (mouse_button.just_pressed(MouseButton::Right) && !draw.points.is_empty());
+    if shape == ObstacleShapeKind::Polygon && close_polygon {
+        if let Some(obstacle) = Obstacle::polygon(&draw.points) {
+            commands.spawn((Name::new("Obstacle"), obstacle));
+            history.mark_edited();
+        }
+        draw.clear();
+        return;
+    }
+
+    let Some(world_pos) = cursor_world_pos(&windows, &cameras) else {
+        return;
+    };
+
+    if mouse_button.just_pressed(MouseButton::Right) && input_state.can_interact_with_world() {
+        if let Some(entity) = pick_obstacle_at(world_pos, &obstacles) {
+            commands.entity(entity).despawn();
+            history.mark_edited();
+        }
+        return;
+    }
+
+    if mouse_button.just_pressed(MouseButton::Left) && input_state.can_interact_with_world() {
+        match shape {
+            ObstacleShapeKind::Polygon => draw.points.push(world_pos),
+            ObstacleShapeKind::Circle | ObstacleShapeKind::Box => draw.drag_start = Some(world_pos),
+        }
+        return;
+    }
+
+    if mouse_button.just_released(MouseButton::Left) {
+        let Some(start) = draw.drag_start.take() else {
+            return;
+        };
+        if let Some(obstacle) = dragged_obstacle(shape, start, world_pos) {
+            commands.spawn((Name::new("Obstacle"), obstacle));
+            history.mark_edited();
+        }
+    }
+}
+
+/// Outlines the obstacle or arena being drawn, up to the cursor.
+pub fn render_outline_preview(
+    mut gizmos: Gizmos,
+    tool_state: Res<EditorToolState>,
+    draw: Res<OutlineDrawState>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    let drawing_tool = matches!(tool_state.active, EditorTool::Obstacle | EditorTool::DrawArena);
+    if !drawing_tool || !draw.is_drawing() {
+        return;
+    }
+    let Some(world_pos) = cursor_world_pos(&windows, &cameras) else {
+        return;
+    };
+
+    if let Some(start) = draw.drag_start {
+        if let Some(obstacle) = dragged_obstacle(tool_state.obstacle_shape, start, world_pos) {
+            let outline = obstacle.outline(OBSTACLE_CIRCLE_SEGMENTS);
+            gizmos.linestrip_2d(outline.iter().copied().chain(outline.first().copied()), OBSTACLE_PREVIEW_COLOR);
+        }
+    } else {
+        gizmos.linestrip_2d(draw.points.iter().copied().chain([world_pos]), OBSTACLE_PREVIEW_COLOR);
+    }
+}
+
+/// Grabs an obstacle under the cursor and moves it with the cursor tool, unless a node is on top.
+pub fn handle_obstacle_drag(
+    mut drag: ResMut<HandleDragState>,
+    mut history: ResMut<EditorHistory>,
+    mut grab_offset: Local<Vec2>,
+    tool_state: Res<EditorToolState>,
+    input_state: Res<InputState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    node_query: Query<(Entity, &Transform, &SimNode), With<Selectable>>,
+    mut obstacles: Query<(Entity, &mut Obstacle)>,
+) {
+    if tool_state.active != EditorTool::Cursor || !drag.update_release(&mouse_button) {
+        return;
+    }
+    let Some(world_pos) = cursor_world_pos(&windows, &cameras) else {
+        return;
+    };
+
+    if mouse_button.just_pressed(MouseButton::Left)
+        && drag.target.is_none()
+        && input_state.can_interact_with_world()
+        && pick_node_at(world_pos, 0.0, &node_query).is_none()
+    {
+        let picked = obstacles
+            .iter()
+            .map(|(entity, obstacle)| (entity, obstacle.signed_distance(world_pos).0, obstacle.position))
+            .filter(|(_, distance, _)| *distance <= 0.0)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((entity, _, position)) = picked {
+            *grab_offset = position - world_pos;
+            drag.target = Some(DragHandle::Obstacle(entity));
+        }
+    }
+
+    let Some(DragHandle::Obstacle(entity)) = drag.target else {
+        return;
+    };
+    if let Ok((_, mut obstacle)) = obstacles.get_mut(entity) {
+        let position = world_pos + *grab_offset;
+        if obstacle.position != position {
+            obstacle.position = position;
+            history.mark_edited();
+        }
+    }
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+/// Deepest obstacle containing `world_pos`, so the innermost of nested shapes wins.
+fn pick_obstacle_at(world_pos: Vec2, obstacles: &Query<(Entity, &Obstacle)>) -> Option<Entity> {
+    obstacles
+        .iter()
+        .map(|(entity, obstacle)| (entity, obstacle.signed_distance(world_pos).0))
+        .filter(|(_, distance)| *distance <= 0.0)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// Circle centered on `start` or box spanning `start..end`; `None` for polygons or tiny drags.
+fn dragged_obstacle(shape: ObstacleShapeKind, start: Vec2, end: Vec2) -> Option<Obstacle> {
+    match shape {
+        ObstacleShapeKind::Circle => {
+            let radius = start.distance(end);
+            (radius >= OBSTACLE_MIN_SIZE).then(|| Obstacle::circle(start, radius))
+        }
+        ObstacleShapeKind::Box => {
+            let half_size = (end - start).abs() * 0.5;
+            (half_size.min_element() * 2.0 >= OBSTACLE_MIN_SIZE).then(|| Obstacle::rect((start + end) * 0.5, half_size))
+        }
+        ObstacleShapeKind::Polygon => None,
+    }
+}
@@ -0,0 +1,45 @@
+//! Dragging world pins around the viewport.
+
+use bevy::prelude::*;
+
+use super::input::{DragHandle, HandleDragState, cursor_world_pos, pick_handle_at};
+use crate::core::Pin;
+use crate::editor::constants::*;
+use crate::editor::resources::EditorHistory;
+use crate::ui::state::{EditorTool, EditorToolState, InputState};
+
+/// Grabs the nearest pin marker under the cursor and moves it until the button is released.
+///
+/// Runs before selection, which ignores the click while a handle is held.
+pub fn handle_pin_drag(
+    mut drag: ResMut<HandleDragState>,
+    mut history: ResMut<EditorHistory>,
+    tool_state: Res<EditorToolState>,
+    input_state: Res<InputState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut pins: Query<(Entity, &mut Pin)>,
+) {
+    if tool_state.active != EditorTool::Cursor || !drag.update_release(&mouse_button) {
+        return;
+    }
+    let Some(world_pos) = cursor_world_pos(&windows, &cameras) else {
+        return;
+    };
+
+    if mouse_button.just_pressed(MouseButton::Left) && drag.target.is_none() && input_state.can_interact_with_world() {
+        let handles = pins.iter().map(|(entity, pin)| (entity, pin.point));
+        drag.target = pick_handle_at(world_pos, PIN_HANDLE_RADIUS, handles).map(DragHandle::Pin);
+    }
+
+    let Some(DragHandle::Pin(node)) = drag.target else {
+        return;
+    };
+    if let Ok((_, mut pin)) = pins.get_mut(node) {
+        if pin.point != world_pos {
+            pin.point = world_pos;
+            history.mark_edited();
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Drawing a custom polygon outline for the playground.
+
+use bevy::prelude::*;
+
+use super::input::{OutlineDrawState, cursor_world_pos};
+use crate::core::{Playground, PlaygroundShape};
+use crate::editor::resources::EditorHistory;
+use crate::ui::state::{EditorTool, EditorToolState, InputState};
+
+/// Collects clicked corners and, once closed, makes them the playground's polygon outline.
+pub fn handle_arena_outline_tool(
+    mut tool_state: ResMut<EditorToolState>,
+    mut draw: ResMut<OutlineDrawState>,
+    mut playground: ResMut<Playground>,
+    mut history: ResMut<EditorHistory>,
+    input_state: Res<InputState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    if tool_state.active != EditorTool::DrawArena {
+        draw.release(EditorTool::DrawArena);
+        return;
+    }
+    draw.claim(EditorTool::DrawArena);
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        draw.clear();
+        tool_state.active = EditorTool::Cursor;
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Enter) || mouse_button.just_pressed(MouseButton::Right) {
+        if draw.points.len() >= 3 {
+            // Stored in units of the half height so the size slider keeps scaling it.
+            let scale = playground.half_size().y.max(1.0);
+            playground.set_shape(PlaygroundShape::Polygon(draw.points.iter().map(|p| *p / scale).collect()));
+            history.mark_edited();
+        }
+        draw.clear();
+        tool_state.active = EditorTool::Cursor;
+        return;
+    }
+
+    if mouse_button.just_pressed(MouseButton::Left) && input_state.can_interact_with_world() {
+        if let Some(world_pos) = cursor_world_pos(&windows, &cameras) {
+            draw.points.push(world_pos);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Prefab capture and the stamp tool that places them.
+
+use bevy::prelude::*;
+
+use super::input::cursor_world_pos;
+use crate::core::components::LimbSet;
+use crate::core::{ConstraintGraph, DistanceConstraint, Node as SimNode, Pin, Prefab, PrefabLibrary, build_subgraph_data, spawn_prefab};
+use crate::editor::resources::EditorHistory;
+use crate::ui::state::{EditorTool, EditorToolState, InputState};
+
+/// Places the armed prefab at the cursor on left-click; right-click or Escape puts the tool down.
+pub fn handle_prefab_stamp(
+    mut commands: Commands,
+    mut tool_state: ResMut<EditorToolState>,
+    mut library: ResMut<PrefabLibrary>,
+    mut history: ResMut<EditorHistory>,
+    input_state: Res<InputState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    if tool_state.active != EditorTool::Stamp {
+        if library.armed.is_some() {
+            library.armed = None;
+        }
+        return;
+    }
+
+    if mouse_button.just_pressed(MouseButton::Right) || keyboard.just_pressed(KeyCode::Escape) {
+        tool_state.active = EditorTool::Cursor;
+        return;
+    }
+
+    if !mouse_button.just_pressed(MouseButton::Left) || !input_state.can_interact_with_world() {
+        return;
+    }
+    let Some(world_pos) = cursor_world_pos(&windows, &cameras) else {
+        return;
+    };
+    let Some(prefab) = library.armed_prefab() else {
+        tool_state.active = EditorTool::Cursor;
+        return;
+    };
+
+    spawn_prefab(&mut commands, prefab, world_pos);
+    history.mark_edited();
+}
+
+/// Captures the connected group containing `root` as a prefab.
+pub fn capture_group_prefab(
+    name: &str,
+    root: Entity,
+    node_query: &Query<(Entity, &mut SimNode)>,
+    constraint_query: &Query<(Entity, &DistanceConstraint)>,
+    limb_set_query: &Query<(Entity, &mut LimbSet)>,
+    pin_query: &Query<(Entity, &Pin)>,
+) -> Prefab {
+    // The shared graph is only rebuilt while playing, so build a fresh one from the current edges.
+    let mut graph = ConstraintGraph::new();
+    let constraint_list: Vec<&DistanceConstraint> = constraint_query.iter().map(|(_, c)| c).collect();
+    graph.rebuild(&constraint_list);
+
+    let members: Vec<Entity> = match graph.get_group(root) {
+        Some(group) => graph.node_groups.iter().filter(|(_, g)| **g == group).map(|(e, _)| *e).collect(),
+        None => vec![root],
+    };
+
+    Prefab::new(name, build_subgraph_data(node_query, constraint_query, limb_set_query, pin_query, &members))
+}
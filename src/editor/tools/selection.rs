@@ -3,48 +3,91 @@
 use bevy::prelude::*;
 
 use super::input::{cursor_world_pos, pick_all_nodes_at};
-use crate::core::{DistanceConstraint, Node as SimNode};
-use crate::editor::components::{NodeVisual, Selectable, Selected};
+use crate::core::{ConstraintGraph, DistanceConstraint, Node as SimNode};
+use crate::editor::components::{BoxSelectPreview, NodeVisual, Selectable, Selected};
 use crate::editor::constants::*;
+use crate::editor::mesh::primitives::create_hollow_rect_mesh;
+use crate::editor::resources::EditorHistory;
 use crate::editor::visuals::node::get_node_color;
 use crate::ui::state::InputState;
 use crate::ui::state::{EditorTool, EditorToolState};
 
 #[derive(Resource, Clone, Debug, Default, Reflect)]
 pub struct Selection {
+    /// Primary node, shown in the inspector and followed by the camera.
     pub entity: Option<Entity>,
+    /// Every selected node in selection order; includes `entity`.
+    pub entities: Vec<Entity>,
     pub last_clicked_index: usize,
 }
 
 impl Selection {
     pub fn select(&mut self, entity: Entity) {
         self.entity = Some(entity);
+        self.entities = vec![entity];
+    }
+
+    pub fn add(&mut self, entity: Entity) {
+        if !self.entities.contains(&entity) {
+            self.entities.push(entity);
+        }
+        self.entity = Some(entity);
+    }
+
+    pub fn toggle(&mut self, entity: Entity) {
+        if self.is_selected(entity) {
+            self.entities.retain(|e| *e != entity);
+            self.entity = self.entities.last().copied();
+        } else {
+            self.add(entity);
+        }
     }
 
     pub fn deselect(&mut self) {
         self.entity = None;
+        self.entities.clear();
     }
 
     pub fn is_selected(&self, entity: Entity) -> bool {
-        self.entity == Some(entity)
+        self.entities.contains(&entity)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+/// World-space corners of an in-progress rubber-band selection.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct BoxSelectState {
+    pub start: Option<Vec2>,
+    pub current: Vec2,
+    pub additive: bool,
+}
+
+impl BoxSelectState {
+    pub fn rect(&self) -> Option<Rect> {
+        self.start.map(|start| Rect::from_corners(start, self.current))
     }
 }
 
 pub fn handle_node_selection(
-    mut commands: Commands,
     mut selection: ResMut<Selection>,
+    mut box_select: ResMut<BoxSelectState>,
     mut camera_state: ResMut<super::camera::CameraState>,
+    handle_drag: Res<super::input::HandleDragState>,
     tool_state: Res<EditorToolState>,
     input_state: Res<InputState>,
     mouse_button: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
     windows: Query<&Window>,
     cameras: Query<(&Camera, &GlobalTransform)>,
     node_query: Query<(Entity, &Transform, &SimNode), With<Selectable>>,
-    selected_query: Query<Entity, With<Selected>>,
 ) {
     if tool_state.active != EditorTool::Cursor
         || !mouse_button.just_pressed(MouseButton::Left)
         || !input_state.can_interact_with_world()
+        || handle_drag.target.is_some()
     {
         return;
     }
@@ -53,28 +96,166 @@ pub fn handle_node_selection(
         return;
     };
     let hits = pick_all_nodes_at(world_pos, 0.0, &node_query);
+    let additive = is_additive_modifier(&keyboard);
 
-    for prev_selected in selected_query.iter() {
-        commands.entity(prev_selected).remove::<Selected>();
+    if hits.is_empty() {
+        if !additive {
+            selection.deselect();
+        }
+        box_select.start = Some(world_pos);
+        box_select.current = world_pos;
+        box_select.additive = additive;
+        return;
     }
 
-    if hits.is_empty() {
-        selection.deselect();
-    } else {
-        let next_index = if let Some(current) = selection.entity {
-            if let Some(pos) = hits.iter().position(|e| *e == current) {
-                (pos + 1) % hits.len()
-            } else {
-                0
-            }
+    if additive {
+        selection.toggle(hits[0]);
+        return;
+    }
+
+    let next_index = if let Some(current) = selection.entity {
+        if let Some(pos) = hits.iter().position(|e| *e == current) {
+            (pos + 1) % hits.len()
         } else {
             0
-        };
+        }
+    } else {
+        0
+    };
 
-        let entity = hits[next_index];
-        selection.select(entity);
-        selection.last_clicked_index = next_index;
-        commands.entity(entity).insert(Selected);
+    selection.select(hits[next_index]);
+    selection.last_clicked_index = next_index;
+}
+
+/// Tracks the rubber-band drag and selects every node whose center ends up inside it.
+pub fn handle_box_select(
+    mut selection: ResMut<Selection>,
+    mut box_select: ResMut<BoxSelectState>,
+    tool_state: Res<EditorToolState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    node_query: Query<(Entity, &SimNode), With<Selectable>>,
+) {
+    if box_select.start.is_none() {
+        return;
+    }
+    if tool_state.active != EditorTool::Cursor {
+        box_select.start = None;
+        return;
+    }
+
+    if let Some(world_pos) = cursor_world_pos(&windows, &cameras) {
+        box_select.current = world_pos;
+    }
+    if !mouse_button.just_released(MouseButton::Left) {
+        return;
+    }
+
+    let Some(rect) = box_select.rect() else { return };
+    box_select.start = None;
+    if rect.width() < BOX_SELECT_MIN_SIZE && rect.height() < BOX_SELECT_MIN_SIZE {
+        return;
+    }
+
+    if !box_select.additive {
+        selection.deselect();
+    }
+    let mut inside: Vec<Entity> = node_query
+        .iter()
+        .filter(|(_, node)| rect.contains(node.position))
+        .map(|(entity, _)| entity)
+        .collect();
+    inside.sort();
+    for entity in inside {
+        selection.add(entity);
+    }
+}
+
+/// Extends the selection to every node sharing a constraint group with it (L).
+pub fn handle_select_connected(
+    mut selection: ResMut<Selection>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input_state: Res<InputState>,
+    constraints: Query<&DistanceConstraint>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyL) || selection.is_empty() || !input_state.can_interact_with_world() {
+        return;
+    }
+
+    // The shared graph is only rebuilt while playing, so build a fresh one from the current edges.
+    let mut graph = ConstraintGraph::new();
+    let constraint_list: Vec<&DistanceConstraint> = constraints.iter().collect();
+    graph.rebuild(&constraint_list);
+
+    let groups: Vec<u32> = selection.entities.iter().filter_map(|e| graph.get_group(*e)).collect();
+    let mut connected: Vec<Entity> = graph
+        .node_groups
+        .iter()
+        .filter(|(_, group)| groups.contains(group))
+        .map(|(entity, _)| *entity)
+        .collect();
+    connected.sort();
+
+    let primary = selection.entity;
+    for entity in connected {
+        selection.add(entity);
+    }
+    selection.entity = primary;
+}
+
+/// Keeps the `Selected` marker in step with the `Selection` resource.
+pub fn sync_selected_markers(
+    mut commands: Commands,
+    selection: Res<Selection>,
+    selected_query: Query<Entity, With<Selected>>,
+) {
+    if !selection.is_changed() {
+        return;
+    }
+
+    for entity in selected_query.iter() {
+        if !selection.is_selected(entity) {
+            commands.entity(entity).remove::<Selected>();
+        }
+    }
+    for &entity in &selection.entities {
+        if !selected_query.contains(entity) {
+            if let Ok(mut entity_commands) = commands.get_entity(entity) {
+                entity_commands.insert(Selected);
+            }
+        }
+    }
+}
+
+/// Draws the rubber-band rectangle while a box selection is in progress.
+pub fn render_box_select_preview(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    box_select: Res<BoxSelectState>,
+    mut preview_query: Query<(Entity, &mut Mesh2d), With<BoxSelectPreview>>,
+) {
+    let Some(rect) = box_select.rect() else {
+        for (entity, _) in preview_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let inner = rect.inflate(-BOX_SELECT_THICKNESS);
+    let mesh = create_hollow_rect_mesh(rect.min, rect.max, inner.min, inner.max);
+
+    if let Ok((_entity, mut mesh_handle)) = preview_query.single_mut() {
+        mesh_handle.0 = meshes.add(mesh);
+    } else {
+        commands.spawn((
+            Name::new("Box Select Preview"),
+            BoxSelectPreview,
+            Mesh2d(meshes.add(mesh)),
+            MeshMaterial2d(materials.add(ColorMaterial::from_color(BOX_SELECT_COLOR))),
+            Transform::from_translation(Vec3::Z * 1.0),
+        ));
     }
 }
 
@@ -110,21 +291,31 @@ pub fn update_selection_visuals(
 pub fn handle_delete_selected(
     mut commands: Commands,
     mut selection: ResMut<Selection>,
+    mut history: ResMut<EditorHistory>,
     keyboard: Res<ButtonInput<KeyCode>>,
     constraints: Query<(Entity, &DistanceConstraint)>,
 ) {
-    if !keyboard.just_pressed(KeyCode::Delete) {
+    if !keyboard.just_pressed(KeyCode::Delete) || selection.is_empty() {
         return;
     }
 
-    if let Some(entity) = selection.entity {
-        for (c_entity, constraint) in constraints.iter() {
-            if constraint.involves(entity) {
-                commands.entity(c_entity).despawn();
-            }
+    for (c_entity, constraint) in constraints.iter() {
+        if selection.entities.iter().any(|e| constraint.involves(*e)) {
+            commands.entity(c_entity).despawn();
         }
-
+    }
+    for &entity in &selection.entities {
         commands.entity(entity).despawn();
-        selection.deselect();
     }
+
+    selection.deselect();
+    history.mark_edited();
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+fn is_additive_modifier(keyboard: &ButtonInput<KeyCode>) -> bool {
+    keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight])
 }
@@ -2,10 +2,11 @@
 
 use bevy::prelude::*;
 
-use crate::editor::mesh::primitives::create_line_mesh;
+use crate::editor::mesh::primitives::{create_dashed_line_mesh, create_line_mesh};
 use crate::core::{DistanceConstraint, Node};
 use crate::editor::components::{ConstraintVisual, ConstraintVisualOf};
 use crate::editor::constants::*;
+use crate::editor::resources::OffscreenCulling;
 use crate::ui::state::DisplaySettings;
 
 pub fn spawn_constraint_visuals(
@@ -23,8 +24,8 @@ pub fn spawn_constraint_visuals(
             continue;
         };
 
-        let (start, end) = edge_endpoints(node_a, node_b);
-        let mesh = create_line_mesh(start, end, CONSTRAINT_LINE_THICKNESS);
+        let (start, end) = edge_endpoints(node_a, node_b, 1.0);
+        let mesh = constraint_mesh(constraint, start, end);
 
         commands.spawn((
             Name::new("Constraint Visual"),
@@ -40,10 +41,14 @@ pub fn spawn_constraint_visuals(
 pub fn sync_constraint_visuals(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
+    fixed_time: Res<Time<Fixed>>,
+    culling: Res<OffscreenCulling>,
     constraints: Query<(Entity, &DistanceConstraint)>,
     nodes: Query<&Node>,
     mut visuals: Query<(Entity, &ConstraintVisualOf, &Mesh2d)>,
 ) {
+    let alpha = fixed_time.overstep_fraction();
+
     for (vis_entity, vis_of, mesh_handle) in visuals.iter_mut() {
         let Ok((_, constraint)) = constraints.get(vis_of.0) else {
             commands.entity(vis_entity).despawn();
@@ -60,9 +65,13 @@ pub fn sync_constraint_visuals(
             continue;
         };
 
-        let (start, end) = edge_endpoints(node_a, node_b);
+        if culling.is_culled(constraint.node_a) {
+            continue;
+        }
+
+        let (start, end) = edge_endpoints(node_a, node_b, alpha);
         if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
-            *mesh = create_line_mesh(start, end, CONSTRAINT_LINE_THICKNESS);
+            *mesh = constraint_mesh(constraint, start, end);
         }
     }
 }
@@ -90,12 +99,23 @@ pub fn update_edge_visibility(
 // Private Methods
 // =============================================================================
 
-fn edge_endpoints(a: &Node, b: &Node) -> (Vec2, Vec2) {
-    let dir = b.position - a.position;
+/// Slack constraints (anything but exact) are drawn dashed so ropes read differently from bones.
+fn constraint_mesh(constraint: &DistanceConstraint, start: Vec2, end: Vec2) -> Mesh {
+    if constraint.is_slack() {
+        create_dashed_line_mesh(start, end, CONSTRAINT_LINE_THICKNESS, CONSTRAINT_DASH_LENGTH, CONSTRAINT_GAP_LENGTH)
+    } else {
+        create_line_mesh(start, end, CONSTRAINT_LINE_THICKNESS)
+    }
+}
+
+fn edge_endpoints(a: &Node, b: &Node, alpha: f32) -> (Vec2, Vec2) {
+    let pos_a = a.interpolated_position(alpha);
+    let pos_b = b.interpolated_position(alpha);
+    let dir = pos_b - pos_a;
     let dist = dir.length();
     if dist < 1e-6 {
-        return (a.position, b.position);
+        return (pos_a, pos_b);
     }
     let norm = dir.normalize();
-    (a.position + norm * a.radius, b.position - norm * b.radius)
+    (pos_a + norm * a.radius, pos_b - norm * b.radius)
 }
@@ -0,0 +1,64 @@
+//! Off-screen culling of creature visuals.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::core::components::{BoundaryMode, Node, Playground};
+use crate::core::resources::ConstraintGraph;
+use crate::editor::constants::CULL_MARGIN;
+use crate::editor::resources::OffscreenCulling;
+
+use std::collections::HashSet;
+
+/// Marks every connected group with no node near the camera view as culled.
+///
+/// Wrapping playgrounds are never culled, since bodies there are also drawn across the seam.
+pub fn update_offscreen_culling(
+    playground: Res<Playground>,
+    graph: Res<ConstraintGraph>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    nodes: Query<(Entity, &Node)>,
+    mut culling: ResMut<OffscreenCulling>,
+) {
+    let view = match playground.boundary_mode {
+        BoundaryMode::Wrap => None,
+        _ => calculate_view_rect(&windows, &camera_query),
+    };
+    let Some(view) = view else {
+        culling.update(HashSet::new());
+        return;
+    };
+
+    let in_view = |node: &Node| view.inflate(node.radius).contains(node.position);
+    let visible_groups: HashSet<u32> = nodes
+        .iter()
+        .filter(|(_, node)| in_view(node))
+        .filter_map(|(entity, _)| graph.get_group(entity))
+        .collect();
+
+    let culled = nodes
+        .iter()
+        .filter(|(entity, node)| match graph.get_group(*entity) {
+            Some(group) => !visible_groups.contains(&group),
+            None => !in_view(node),
+        })
+        .map(|(entity, _)| entity)
+        .collect();
+    culling.update(culled);
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+fn calculate_view_rect(
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+) -> Option<Rect> {
+    let window = windows.single().ok()?;
+    let (camera, camera_transform) = camera_query.single().ok()?;
+    let corner_a = camera.viewport_to_world_2d(camera_transform, Vec2::ZERO).ok()?;
+    let corner_b = camera.viewport_to_world_2d(camera_transform, window.size()).ok()?;
+    Some(Rect::from_corners(corner_a, corner_b).inflate(CULL_MARGIN))
+}
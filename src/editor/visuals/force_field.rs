@@ -0,0 +1,41 @@
+//! Gizmo drawing for force fields.
+
+use bevy::prelude::*;
+
+use crate::core::{ForceField, SimulationClock, SimulationRng};
+use crate::editor::constants::*;
+use crate::ui::state::DisplaySettings;
+
+/// Draws each field's reach, its drag handle, and a grid of arrows sampling the current push.
+pub fn draw_force_field_gizmos(
+    mut gizmos: Gizmos,
+    display_settings: Res<DisplaySettings>,
+    clock: Res<SimulationClock>,
+    rng: Res<SimulationRng>,
+    fields: Query<&ForceField>,
+) {
+    if !display_settings.show_debug {
+        return;
+    }
+
+    for field in fields.iter() {
+        gizmos.circle_2d(field.position, field.radius, FIELD_COLOR);
+        gizmos.circle_2d(field.position, FIELD_HANDLE_RADIUS, FIELD_ARROW_COLOR);
+
+        let spacing = field.radius * 2.0 / FIELD_GIZMO_SAMPLES as f32;
+        let arrow_scale = spacing * 0.8 / field.strength.abs().max(f32::EPSILON);
+        let origin = field.position - Vec2::splat(field.radius - spacing * 0.5);
+        for row in 0..FIELD_GIZMO_SAMPLES {
+            for column in 0..FIELD_GIZMO_SAMPLES {
+                let sample = origin + Vec2::new(column as f32, row as f32) * spacing;
+                if sample.distance(field.position) > field.radius {
+                    continue;
+                }
+                let push = field.acceleration_at(sample, clock.elapsed_secs, rng.seed) * arrow_scale;
+                if push.length_squared() > 1.0 {
+                    gizmos.arrow_2d(sample, sample + push, FIELD_ARROW_COLOR);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Gizmo drawing for limb joint bend limits.
+
+use bevy::prelude::*;
+
+use crate::core::{LimbSet, Node};
+use crate::editor::constants::*;
+use crate::editor::resources::OffscreenCulling;
+use crate::ui::state::DisplaySettings;
+
+use std::f32::consts::FRAC_PI_2;
+
+/// Draws the range each limb knee may swing through, measured from its incoming segment.
+pub fn draw_joint_limit_gizmos(
+    mut gizmos: Gizmos,
+    display_settings: Res<DisplaySettings>,
+    fixed_time: Res<Time<Fixed>>,
+    culling: Res<OffscreenCulling>,
+    limb_sets: Query<(Entity, &LimbSet)>,
+    nodes: Query<&Node>,
+) {
+    if !display_settings.show_debug {
+        return;
+    }
+
+    let alpha = fixed_time.overstep_fraction();
+
+    for (body_entity, limb_set) in limb_sets.iter() {
+        if culling.is_culled(body_entity) {
+            continue;
+        }
+        let Ok(body_node) = nodes.get(body_entity) else {
+            continue;
+        };
+
+        for limb in &limb_set.limbs {
+            let mut parent = body_node.interpolated_position(alpha);
+            // The foot has no outgoing segment to limit.
+            for (i, &joint_entity) in limb.joints.iter().enumerate().take(limb.joints.len().saturating_sub(1)) {
+                let Ok(joint_node) = nodes.get(joint_entity) else {
+                    break;
+                };
+                let joint = joint_node.interpolated_position(alpha);
+                let incoming = (joint - parent).to_angle();
+                let limit = limb.joint_limit(i);
+                let radius = joint_node.radius * JOINT_LIMIT_ARC_SCALE;
+
+                let start = incoming + limit.min.min(limit.max);
+                let sweep = (limit.max - limit.min).abs();
+                gizmos.arc_2d(Isometry2d::new(joint, Rot2::radians(start - FRAC_PI_2)), sweep, radius, JOINT_LIMIT_COLOR);
+                gizmos.line_2d(joint, joint + Vec2::from_angle(start) * radius, JOINT_LIMIT_COLOR);
+                gizmos.line_2d(joint, joint + Vec2::from_angle(start + sweep) * radius, JOINT_LIMIT_COLOR);
+
+                parent = joint;
+            }
+        }
+    }
+}
@@ -1,12 +1,22 @@
 //! Editor visual spawning, syncing, and mesh utilities.
 
 pub mod constraint;
+pub mod culling;
+pub mod force_field;
+pub mod joint_limit;
 pub mod node;
+pub mod obstacle;
 pub mod params;
+pub mod pin;
 pub mod playground;
 pub mod skin;
 
 pub use constraint::*;
+pub use culling::*;
+pub use force_field::*;
+pub use joint_limit::*;
 pub use node::*;
+pub use obstacle::*;
+pub use pin::*;
 pub use playground::*;
 pub use skin::*;
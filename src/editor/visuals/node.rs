@@ -12,6 +12,7 @@ use crate::editor::components::{
     AngleArc, ContactPoint, DirectionVector, EyeVisual, LookVector, NodeVisual, NodeVisualCache, NodeVisualOf, Selectable, TargetMarker,
 };
 use crate::editor::constants::*;
+use crate::editor::resources::OffscreenCulling;
 use crate::ui::state::DisplaySettings;
 
 pub fn get_node_color(node_type: NodeType) -> Color {
@@ -91,9 +92,13 @@ pub fn sync_node_visuals(
     mut iter_params: NodeIterationParams,
     mut sync_params: NodeSyncParams,
 ) {
-    for (entity, node, mut transform, children, limb_set, mut cache) in iter_params.query.iter_mut() {
-        transform.translation.x = node.position.x;
-        transform.translation.y = node.position.y;
+    let alpha = sync_params.fixed_time.overstep_fraction();
+
+    for (entity, node, children, limb_set, mut cache) in iter_params.query.iter_mut() {
+        let culling = &sync_params.culling;
+        if culling.is_culled(entity) || !(node.is_changed() || culling.is_revealed(entity)) {
+            continue;
+        }
 
         let is_anchor = node.node_type == NodeType::Anchor;
         let show_target = is_anchor || limb_set.is_some();
@@ -172,7 +177,7 @@ pub fn sync_node_visuals(
                 &mut commands,
                 entity,
                 &targets,
-                node.position,
+                node.interpolated_position(alpha),
                 &mut sync_params,
             );
 
@@ -188,6 +193,26 @@ pub fn sync_node_visuals(
     }
 }
 
+/// Moves node transforms every frame, blending between simulation ticks.
+pub fn interpolate_node_transforms(
+    fixed_time: Res<Time<Fixed>>,
+    culling: Res<OffscreenCulling>,
+    mut nodes: Query<(Entity, &Node, &mut Transform)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (entity, node, mut transform) in nodes.iter_mut() {
+        if culling.is_culled(entity) {
+            continue;
+        }
+
+        let position = node.interpolated_position(alpha);
+        if transform.translation.truncate() != position {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
+    }
+}
+
 pub fn update_node_visibility(mut params: NodeVisibilityParams) {
     if !params.display_settings.is_changed() {
         return;
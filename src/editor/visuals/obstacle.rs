@@ -0,0 +1,64 @@
+//! Visual rendering for static obstacles.
+
+use bevy::prelude::*;
+
+use crate::core::Obstacle;
+use crate::editor::components::{ObstacleFill, ObstacleOutline, ObstacleVisualOf};
+use crate::editor::constants::*;
+use crate::editor::mesh::build_outline_mesh;
+use crate::editor::mesh::primitives::create_convex_polygon_mesh;
+
+pub fn spawn_obstacle_visuals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    new_obstacles: Query<(Entity, &Obstacle), Added<Obstacle>>,
+) {
+    for (entity, obstacle) in new_obstacles.iter() {
+        let outline = obstacle.outline(OBSTACLE_CIRCLE_SEGMENTS);
+
+        commands.spawn((
+            Name::new("Obstacle Fill"),
+            ObstacleFill,
+            ObstacleVisualOf(entity),
+            Mesh2d(meshes.add(create_convex_polygon_mesh(&outline))),
+            MeshMaterial2d(materials.add(ColorMaterial::from_color(OBSTACLE_FILL_COLOR))),
+            Transform::from_translation(Vec3::Z * -0.9),
+        ));
+        commands.spawn((
+            Name::new("Obstacle Outline"),
+            ObstacleOutline,
+            ObstacleVisualOf(entity),
+            Mesh2d(meshes.add(build_outline_mesh(&[outline], OBSTACLE_OUTLINE_THICKNESS))),
+            MeshMaterial2d(materials.add(ColorMaterial::from_color(OBSTACLE_OUTLINE_COLOR))),
+            Transform::from_translation(Vec3::Z * -0.8),
+        ));
+    }
+}
+
+/// Rebuilds obstacle meshes after an obstacle changes and despawns visuals whose obstacle is gone.
+pub fn sync_obstacle_visuals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    obstacles: Query<Ref<Obstacle>>,
+    visuals: Query<(Entity, &ObstacleVisualOf, &Mesh2d, Has<ObstacleOutline>), Or<(With<ObstacleFill>, With<ObstacleOutline>)>>,
+) {
+    for (vis_entity, vis_of, mesh_handle, is_outline) in visuals.iter() {
+        let Ok(obstacle) = obstacles.get(vis_of.0) else {
+            commands.entity(vis_entity).despawn();
+            continue;
+        };
+        if !obstacle.is_changed() || obstacle.is_added() {
+            continue;
+        }
+
+        let outline = obstacle.outline(OBSTACLE_CIRCLE_SEGMENTS);
+        if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+            *mesh = if is_outline {
+                build_outline_mesh(&[outline], OBSTACLE_OUTLINE_THICKNESS)
+            } else {
+                create_convex_polygon_mesh(&outline)
+            };
+        }
+    }
+}
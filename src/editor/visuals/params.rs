@@ -6,15 +6,18 @@ use bevy::prelude::*;
 use crate::core::components::LimbSet;
 use crate::core::Node;
 use crate::editor::components::*;
+use crate::editor::resources::OffscreenCulling;
 use crate::ui::state::DisplaySettings;
 
 #[derive(SystemParam)]
 pub struct NodeIterationParams<'w, 's> {
-    pub query: Query<'w, 's, (Entity, &'static Node, &'static mut Transform, &'static Children, Option<&'static LimbSet>, &'static mut NodeVisualCache), (Changed<Node>, Without<ContactPoint>, Without<LookVector>, Without<EyeVisual>, Without<TargetMarker>, Without<DirectionVector>, Without<AngleArc>)>,
+    pub query: Query<'w, 's, (Entity, Ref<'static, Node>, &'static Children, Option<&'static LimbSet>, &'static mut NodeVisualCache), (Without<ContactPoint>, Without<LookVector>, Without<EyeVisual>, Without<TargetMarker>, Without<DirectionVector>, Without<AngleArc>)>,
 }
 
 #[derive(SystemParam)]
 pub struct NodeSyncParams<'w, 's> {
+    pub fixed_time: Res<'w, Time<Fixed>>,
+    pub culling: Res<'w, OffscreenCulling>,
     pub meshes: ResMut<'w, Assets<Mesh>>,
     pub materials: ResMut<'w, Assets<ColorMaterial>>,
     pub visual_query: Query<'w, 's, &'static mut Mesh2d, With<NodeVisual>>,
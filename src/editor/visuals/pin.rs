@@ -0,0 +1,73 @@
+//! Visual rendering for world pins.
+
+use bevy::prelude::*;
+
+use crate::core::{Node, Pin};
+use crate::editor::components::{PinMarker, PinTether, PinVisualOf};
+use crate::editor::constants::*;
+use crate::editor::mesh::primitives::{create_dashed_line_mesh, create_x_marker_mesh};
+
+pub fn spawn_pin_visuals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    new_pins: Query<(Entity, &Node, &Pin), Added<Pin>>,
+) {
+    for (entity, node, pin) in new_pins.iter() {
+        let material = materials.add(ColorMaterial::from_color(PIN_COLOR));
+
+        commands.spawn((
+            Name::new("Pin Tether"),
+            PinTether,
+            PinVisualOf(entity),
+            Mesh2d(meshes.add(tether_mesh(node.position, pin.point))),
+            MeshMaterial2d(material.clone()),
+            Transform::from_translation(Vec3::Z * 0.5),
+        ));
+        commands.spawn((
+            Name::new("Pin Marker"),
+            PinMarker,
+            PinVisualOf(entity),
+            Mesh2d(meshes.add(create_x_marker_mesh(PIN_MARKER_SIZE, PIN_MARKER_THICKNESS))),
+            MeshMaterial2d(material),
+            Transform::from_translation(pin.point.extend(0.6)),
+        ));
+    }
+}
+
+pub fn sync_pin_visuals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    fixed_time: Res<Time<Fixed>>,
+    pins: Query<(&Node, &Pin)>,
+    tethers: Query<(Entity, &PinVisualOf, &Mesh2d), With<PinTether>>,
+    mut markers: Query<(Entity, &PinVisualOf, &mut Transform), With<PinMarker>>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+
+    for (vis_entity, vis_of, mesh_handle) in tethers.iter() {
+        let Ok((node, pin)) = pins.get(vis_of.0) else {
+            commands.entity(vis_entity).despawn();
+            continue;
+        };
+        if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+            *mesh = tether_mesh(node.interpolated_position(alpha), pin.point);
+        }
+    }
+
+    for (vis_entity, vis_of, mut transform) in markers.iter_mut() {
+        let Ok((_, pin)) = pins.get(vis_of.0) else {
+            commands.entity(vis_entity).despawn();
+            continue;
+        };
+        transform.translation = pin.point.extend(transform.translation.z);
+    }
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+fn tether_mesh(from: Vec2, to: Vec2) -> Mesh {
+    create_dashed_line_mesh(from, to, CONSTRAINT_LINE_THICKNESS, CONSTRAINT_DASH_LENGTH, CONSTRAINT_GAP_LENGTH)
+}
@@ -2,18 +2,19 @@
 
 use bevy::prelude::*;
 
-use crate::editor::mesh::primitives::{create_hollow_rect_mesh, create_quad_mesh};
-use crate::core::Playground;
+use crate::editor::mesh::build_outline_mesh;
+use crate::editor::mesh::primitives::{create_filled_circle_mesh, create_hollow_rect_mesh, create_polygon_mesh, create_quad_mesh};
+use crate::core::{Playground, PlaygroundShape};
 use crate::editor::components::{PlaygroundBorder, PlaygroundFill, PlaygroundOutside};
 use crate::editor::constants::*;
 
-/// Syncs `Playground.half_size` to match the window dimensions in world space.
+/// Syncs the playground's `half_size` to match the window dimensions in world space.
 pub fn sync_playground_to_window(mut playground: ResMut<Playground>, windows: Query<&Window>) {
     let Ok(window) = windows.single() else { return };
     let new_half = Vec2::new(window.width() * 0.5, window.height() * 0.5);
 
-    if (playground.half_size - new_half).length_squared() > 0.1 {
-        playground.half_size = new_half;
+    if (playground.half_size() - new_half).length_squared() > 0.1 {
+        playground.set_half_size(new_half);
     }
 }
 
@@ -24,15 +25,7 @@ pub fn spawn_playground_visual(
     mut materials: ResMut<Assets<ColorMaterial>>,
     playground: Res<Playground>,
 ) {
-    let hs = playground.half_size;
-    let stroke_outer_min = playground.stroke_outer_min();
-    let stroke_outer_max = playground.stroke_outer_max();
-    let inner_min = playground.inner_min();
-    let inner_max = playground.inner_max();
-
-    let outside_mesh = create_hollow_rect_mesh(-hs, hs, stroke_outer_min, stroke_outer_max);
-    let border_mesh = create_hollow_rect_mesh(stroke_outer_min, stroke_outer_max, inner_min, inner_max);
-    let fill_mesh = create_quad_mesh(inner_min, inner_max);
+    let [outside_mesh, border_mesh, fill_mesh] = playground_meshes(&playground);
 
     commands.spawn((
         Name::new("Playground Outside"),
@@ -96,42 +89,62 @@ pub fn sync_playground_visual(
         return;
     }
 
-    let hs = playground.half_size;
-    let stroke_outer_min = playground.stroke_outer_min();
-    let stroke_outer_max = playground.stroke_outer_max();
-    let inner_min = playground.inner_min();
-    let inner_max = playground.inner_max();
+    let [outside_mesh, border_mesh, fill_mesh] = playground_meshes(&playground);
 
     for (mh, mat) in outside_query.iter() {
-        update_layer(
-            &mut meshes,
-            &mut materials,
-            mh,
-            mat,
-            create_hollow_rect_mesh(-hs, hs, stroke_outer_min, stroke_outer_max),
-            PLAYGROUND_OUTSIDE_COLOR,
-        );
+        update_layer(&mut meshes, &mut materials, mh, mat, outside_mesh.clone(), PLAYGROUND_OUTSIDE_COLOR);
     }
 
     for (mh, mat) in border_query.iter() {
-        update_layer(
-            &mut meshes,
-            &mut materials,
-            mh,
-            mat,
-            create_hollow_rect_mesh(stroke_outer_min, stroke_outer_max, inner_min, inner_max),
-            PLAYGROUND_BORDER_COLOR,
-        );
+        update_layer(&mut meshes, &mut materials, mh, mat, border_mesh.clone(), PLAYGROUND_BORDER_COLOR);
     }
 
     for (mh, mat) in fill_query.iter() {
-        update_layer(
-            &mut meshes,
-            &mut materials,
-            mh,
-            mat,
-            create_quad_mesh(inner_min, inner_max),
-            PLAYGROUND_FILL_COLOR,
-        );
+        update_layer(&mut meshes, &mut materials, mh, mat, fill_mesh.clone(), PLAYGROUND_FILL_COLOR);
+    }
+}
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+/// Outside, border and fill meshes for the playground's shape.
+///
+/// Circle and polygon arenas leave the outside empty and stroke the border along the walls.
+fn playground_meshes(playground: &Playground) -> [Mesh; 3] {
+    let stroke = playground.stroke_width;
+    match playground.shape() {
+        PlaygroundShape::Rectangle => {
+            let hs = playground.half_size();
+            let stroke_outer_min = playground.stroke_outer_min();
+            let stroke_outer_max = playground.stroke_outer_max();
+            let inner_min = playground.inner_min();
+            let inner_max = playground.inner_max();
+            [
+                create_hollow_rect_mesh(-hs, hs, stroke_outer_min, stroke_outer_max),
+                create_hollow_rect_mesh(stroke_outer_min, stroke_outer_max, inner_min, inner_max),
+                create_quad_mesh(inner_min, inner_max),
+            ]
+        }
+        PlaygroundShape::Circle => {
+            let radius = playground.inner_radius();
+            let border: Vec<Vec2> = (0..PLAYGROUND_CIRCLE_SEGMENTS)
+                .map(|i| Vec2::from_angle(i as f32 / PLAYGROUND_CIRCLE_SEGMENTS as f32 * std::f32::consts::TAU))
+                .map(|dir| dir * (radius + stroke * 0.5))
+                .collect();
+            [
+                build_outline_mesh(&[], stroke),
+                build_outline_mesh(&[border], stroke),
+                create_filled_circle_mesh(radius, PLAYGROUND_CIRCLE_SEGMENTS),
+            ]
+        }
+        PlaygroundShape::Polygon(_) => {
+            let points = playground.polygon_points().unwrap_or_default();
+            [
+                build_outline_mesh(&[], stroke),
+                build_outline_mesh(&[points.to_vec()], stroke),
+                create_polygon_mesh(points),
+            ]
+        }
     }
 }
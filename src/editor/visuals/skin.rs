@@ -3,13 +3,13 @@
 use bevy::prelude::*;
 use bevy::render::render_resource::PrimitiveTopology;
 
-use crate::core::components::{Node, NodeType};
+use crate::core::components::{Node, NodeType, Playground};
 use crate::core::resources::ConstraintGraph;
 use crate::editor::components::{SkinGroupIndex, SkinMesh, SkinOutline};
 use crate::editor::constants::*;
-use crate::editor::resources::SkinChains;
+use crate::editor::resources::{OffscreenCulling, SkinChains};
 use crate::editor::mesh::skin::{
-    build_outline_mesh, build_strip_fill_mesh, evaluate_catmull_rom_closed, evaluate_catmull_rom_open,
+    append_mesh_copies, build_outline_mesh, build_strip_fill_mesh, evaluate_catmull_rom_closed, evaluate_catmull_rom_open,
 };
 use crate::ui::state::DisplaySettings;
 
@@ -60,6 +60,9 @@ pub fn sync_skin_visual(
     display_settings: Res<DisplaySettings>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    fixed_time: Res<Time<Fixed>>,
+    culling: Res<OffscreenCulling>,
+    playground: Res<Playground>,
     graph: Res<ConstraintGraph>,
     skin_chains: Res<SkinChains>,
     nodes: Query<&Node>,
@@ -80,18 +83,34 @@ pub fn sync_skin_visual(
 ) {
     let show = display_settings.show_skin;
     let opaque = !display_settings.show_nodes;
+    let alpha = fixed_time.overstep_fraction();
 
     let chains = if show { &skin_chains.chains } else { &Vec::new() };
 
     let chain_count = chains.len();
 
-    let mut polygons: Vec<Vec<Vec2>> = Vec::with_capacity(chain_count);
+    let mut polygons: Vec<Vec<Vec<Vec2>>> = Vec::with_capacity(chain_count);
     let mut fill_meshes: Vec<Mesh> = Vec::with_capacity(chain_count);
     let mut chain_group_ids: Vec<u32> = Vec::with_capacity(chain_count);
 
     for chain in chains.iter() {
-        if let Some((polygon, fill)) = build_body_skin(chain, &nodes) {
-            polygons.push(polygon);
+        // Culled chains keep their slot with nothing to draw, so later chains keep their meshes.
+        if culling.is_culled(chain[0].0) {
+            polygons.push(Vec::new());
+            fill_meshes.push(Mesh::new(PrimitiveTopology::TriangleList, default()));
+            chain_group_ids.push(graph.get_group(chain[0].0).unwrap_or(0));
+            continue;
+        }
+
+        if let Some((polygon, mut fill)) = build_body_skin(chain, &nodes, alpha) {
+            // A body straddling the seam of a wrapping playground is drawn again on the far side.
+            let (min, max) = polygon.iter().fold((polygon[0], polygon[0]), |(lo, hi), p| (lo.min(*p), hi.max(*p)));
+            let images = playground.wrap_images(min, max);
+            append_mesh_copies(&mut fill, &images);
+
+            let mut copies = vec![polygon.clone()];
+            copies.extend(images.iter().map(|offset| polygon.iter().map(|p| *p + *offset).collect()));
+            polygons.push(copies);
             fill_meshes.push(fill);
             
             let first_node = chain[0].0;
@@ -134,7 +153,7 @@ pub fn sync_skin_visual(
     for (entity, mesh_handle, mat_handle, group, mut vis) in fill_query.iter_mut() {
         let idx = group.0;
 
-        if !show || idx >= polygons.len() {
+        if !show || polygons.get(idx).is_none_or(Vec::is_empty) {
             if idx >= chain_count {
                 fill_entities_to_despawn.push(entity);
             } else {
@@ -165,7 +184,7 @@ pub fn sync_skin_visual(
     for (entity, mesh_handle, group, mut vis) in outline_query.iter_mut() {
         let idx = group.0;
 
-        if !show || idx >= polygons.len() {
+        if !show || polygons.get(idx).is_none_or(Vec::is_empty) {
             if idx >= chain_count {
                 outline_entities_to_despawn.push(entity);
             } else {
@@ -180,7 +199,7 @@ pub fn sync_skin_visual(
         *vis = Visibility::Inherited;
 
         if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
-            *mesh = build_outline_mesh(&[polygons[idx].clone()], OUTLINE_THICKNESS);
+            *mesh = build_outline_mesh(&polygons[idx], OUTLINE_THICKNESS);
         }
     }
 
@@ -295,12 +314,12 @@ fn trace_chain(
     chain
 }
 
-fn get_offset_pos(node: &Node, angle_offset: f32, length_offset: f32) -> Vec2 {
-    node.position + Vec2::from_angle(node.chain_angle + PI + angle_offset) * (node.radius + length_offset)
+fn get_offset_pos(node: &Node, position: Vec2, angle_offset: f32, length_offset: f32) -> Vec2 {
+    position + Vec2::from_angle(node.chain_angle + PI + angle_offset) * (node.radius + length_offset)
 }
 
 /// Builds both the outline polygon and the strip fill mesh in one pass.
-fn build_body_skin(chain: &[(Entity, f32)], nodes: &Query<&Node>) -> Option<(Vec<Vec2>, Mesh)> {
+fn build_body_skin(chain: &[(Entity, f32)], nodes: &Query<&Node>, alpha: f32) -> Option<(Vec<Vec2>, Mesh)> {
     let chain_nodes: Vec<&Node> = chain
         .iter()
         .filter_map(|&(entity, _)| nodes.get(entity).ok())
@@ -312,12 +331,13 @@ fn build_body_skin(chain: &[(Entity, f32)], nodes: &Query<&Node>) -> Option<(Vec
 
     let node_count = chain_nodes.len();
     let last = node_count - 1;
+    let positions: Vec<Vec2> = chain_nodes.iter().map(|n| n.interpolated_position(alpha)).collect();
 
     let left_ctrl: Vec<Vec2> = (0..node_count)
-        .map(|i| get_offset_pos(chain_nodes[i], FRAC_PI_2, 0.0))
+        .map(|i| get_offset_pos(chain_nodes[i], positions[i], FRAC_PI_2, 0.0))
         .collect();
     let right_ctrl: Vec<Vec2> = (0..node_count)
-        .map(|i| get_offset_pos(chain_nodes[i], -FRAC_PI_2, 0.0))
+        .map(|i| get_offset_pos(chain_nodes[i], positions[i], -FRAC_PI_2, 0.0))
         .collect();
 
     let overlap_count = node_count.min(3);
@@ -327,13 +347,13 @@ fn build_body_skin(chain: &[(Entity, f32)], nodes: &Query<&Node>) -> Option<(Vec
     for i in 0..node_count {
         control_points.push(left_ctrl[i]);
     }
-    control_points.push(get_offset_pos(chain_nodes[last], PI, 0.0));
+    control_points.push(get_offset_pos(chain_nodes[last], positions[last], PI, 0.0));
     for i in (0..node_count).rev() {
         control_points.push(right_ctrl[i]);
     }
-    control_points.push(get_offset_pos(chain_nodes[0], -FRAC_PI_6, 0.0));
-    control_points.push(get_offset_pos(chain_nodes[0], 0.0, 0.0));
-    control_points.push(get_offset_pos(chain_nodes[0], FRAC_PI_6, 0.0));
+    control_points.push(get_offset_pos(chain_nodes[0], positions[0], -FRAC_PI_6, 0.0));
+    control_points.push(get_offset_pos(chain_nodes[0], positions[0], 0.0, 0.0));
+    control_points.push(get_offset_pos(chain_nodes[0], positions[0], FRAC_PI_6, 0.0));
     for i in 0..overlap_count {
         control_points.push(left_ctrl[i]);
     }
@@ -353,7 +373,7 @@ fn build_body_skin(chain: &[(Entity, f32)], nodes: &Query<&Node>) -> Option<(Vec
         .map(|k| {
             let t = k as f32 / CAP_SEGMENTS as f32;
             let angle = head_base - FRAC_PI_2 + PI * t;
-            head.position + Vec2::from_angle(angle) * head.radius
+            positions[0] + Vec2::from_angle(angle) * head.radius
         })
         .collect();
     head_cap[0] = right_smooth[0];
@@ -368,7 +388,7 @@ fn build_body_skin(chain: &[(Entity, f32)], nodes: &Query<&Node>) -> Option<(Vec
         .map(|k| {
             let t = k as f32 / CAP_SEGMENTS as f32;
             let angle = tail_base + FRAC_PI_2 + PI * t;
-            tail.position + Vec2::from_angle(angle) * tail.radius
+            positions[last] + Vec2::from_angle(angle) * tail.radius
         })
         .collect();
     tail_cap[0] = left_smooth[last_l];
@@ -377,9 +397,9 @@ fn build_body_skin(chain: &[(Entity, f32)], nodes: &Query<&Node>) -> Option<(Vec
     let fill = build_strip_fill_mesh(
         &left_smooth,
         &right_smooth,
-        head.position,
+        positions[0],
         &head_cap,
-        tail.position,
+        positions[last],
         &tail_cap,
     );
 
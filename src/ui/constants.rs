@@ -49,6 +49,10 @@ pub const LIMB_ANGLE_OFFSET_RANGE: RangeInclusive<f32> = -180.0..=180.0;
 pub const LIMB_STEP_THRESHOLD_RANGE: RangeInclusive<f32> = 0.0..=200.0;
 pub const LIMB_STEP_SPEED_RANGE: RangeInclusive<f32> = 1.0..=20.0;
 pub const LIMB_STEP_HEIGHT_RANGE: RangeInclusive<f32> = 1.0..=100.0;
+pub const LIMB_LEAD_RANGE: RangeInclusive<f32> = 0.0..=2.0;
+pub const LIMB_RELEASE_RANGE: RangeInclusive<f32> = 1.0..=2.0;
+pub const LIMB_PHASE_RANGE: RangeInclusive<usize> = 0..=5;
+pub const LIMB_JOINT_LIMIT_RANGE: RangeInclusive<f32> = -180.0..=180.0;
 
 pub const MOVEMENT_SPEED_RANGE: RangeInclusive<f32> = 1.0..=20.0;
 pub const PATH_AMPLITUDE_RANGE: RangeInclusive<f32> = 10.0..=200.0;
@@ -57,8 +61,22 @@ pub const COLLISION_DAMPING_RANGE: RangeInclusive<f32> = 0.0..=1.0;
 
 pub const NODE_RADIUS_RANGE: RangeInclusive<f32> = 4.0..=150.0;
 
+pub const COMPLIANCE_SMALLEST_POSITIVE: f64 = 1e-6;
+
+pub const FIELD_STRENGTH_RANGE: RangeInclusive<f32> = -2000.0..=2000.0;
+pub const FIELD_RADIUS_RANGE: RangeInclusive<f32> = 20.0..=1000.0;
+pub const FIELD_GUST_RANGE: RangeInclusive<f32> = 0.0..=2.0;
+pub const FIELD_FREQUENCY_RANGE: RangeInclusive<f32> = 0.0..=5.0;
+pub const FIELD_NOISE_SCALE_RANGE: RangeInclusive<f32> = 10.0..=500.0;
+
 pub const PLAYGROUND_HALF_SIZE_RANGE: RangeInclusive<f32> = 400.0..=2000.0;
 
+pub const TICK_RATE_RANGE: RangeInclusive<f64> = 10.0..=240.0;
+
+pub const TIME_SCALE_RANGE: RangeInclusive<f32> = 0.05..=8.0;
+
+pub const STEP_TICKS_RANGE: RangeInclusive<u32> = 1..=600;
+
 // =============================================================================
 // Misc UI Settings
 // =============================================================================
@@ -13,6 +13,34 @@ pub const BTN_EXPORT: &str = "Export";
 pub const LABEL_CLEAR_ON_IMPORT: &str = "Clear on Import";
 pub const LABEL_PLAYGROUND_SIZE: &str = "Playground Size";
 pub const LABEL_HALF_HEIGHT: &str = "Size:";
+pub const LABEL_PLAYGROUND_SHAPE: &str = "Shape:";
+pub const LABEL_BOUNDARY_MODE: &str = "Edges:";
+pub const BTN_DRAW_ARENA: &str = "Draw Outline";
+pub const HINT_DRAW_ARENA: &str = "Click to add points, Enter or right-click to close, Esc to cancel";
+pub const LABEL_SIMULATION: &str = "Simulation";
+pub const LABEL_TICK_RATE: &str = "Tick Rate (Hz):";
+pub const LABEL_SEED: &str = "Seed:";
+pub const LABEL_PREFABS: &str = "Prefabs";
+pub const LABEL_PREFAB_NAME: &str = "Name:";
+pub const BTN_SAVE_PREFAB: &str = "Save Selected Group";
+pub const PLACEHOLDER_NO_PREFABS: &str = "No prefabs saved";
+pub const HINT_STAMP_PREFAB: &str = "Click to place, Esc to stop";
+pub const LABEL_OBSTACLES: &str = "Obstacles";
+pub const HINT_DRAW_OBSTACLE: &str = "Drag to draw, right-click an obstacle to remove it, Esc to stop";
+pub const HINT_DRAW_POLYGON: &str = "Click to add points, Enter or right-click to close, Esc to stop";
+pub const LABEL_FORCE_FIELDS: &str = "Force Fields";
+pub const BTN_ADD_FIELD: &str = "Add";
+pub const BTN_REMOVE_FIELD: &str = "Remove";
+pub const PLACEHOLDER_NO_FIELDS: &str = "No force fields";
+pub const PROP_FIELD_KIND: &str = "Kind:";
+pub const PROP_FIELD_STRENGTH: &str = "Strength:";
+pub const PROP_FIELD_RADIUS: &str = "Radius:";
+pub const PROP_FIELD_FALLOFF: &str = "Falloff:";
+pub const PROP_FIELD_DIRECTION: &str = "Direction:";
+pub const PROP_FIELD_GUST: &str = "Gust:";
+pub const PROP_FIELD_FREQUENCY: &str = "Frequency:";
+pub const PROP_FIELD_NOISE_SCALE: &str = "Noise Scale:";
+pub const TOOLTIP_FIELD_STRENGTH: &str = "Negative strength pushes instead of pulls";
 
 // =============================================================================
 // Inspector Panel
@@ -42,6 +70,9 @@ pub const PROP_ACCELERATION: &str = "Acceleration";
 pub const PROP_RADIUS: &str = "Radius";
 pub const PROP_FOLLOW_MOUSE: &str = "Follow Mouse";
 pub const PROP_IS_HEAD: &str = "Is Head";
+pub const PROP_MASS: &str = "Mass";
+pub const PROP_MASS_AUTO: &str = "Auto";
+pub const TOOLTIP_MASS_AUTO: &str = "Derive mass from the radius";
 
 pub const PROP_ANGLE_MIN: &str = "Angle Min";
 pub const PROP_ANGLE_MAX: &str = "Angle Max";
@@ -51,12 +82,31 @@ pub const PROP_THRESHOLD: &str = "Threshold:";
 pub const PROP_SPEED: &str = "Speed:";
 pub const PROP_HEIGHT: &str = "Height:";
 pub const PROP_JOINT_FLIP: &str = "Joint Flip:";
+pub const PROP_JOINT_LIMITS: &str = "Joint Limits:";
+pub const PROP_SOLVER: &str = "Solver:";
+pub const TOOLTIP_SOLVER: &str = "Two-Bone is exact for two-segment limbs; longer limbs fall back to FABRIK";
+pub const TOOLTIP_JOINT_LIMITS: &str = "How far each knee may bend from straight, counter-clockwise positive";
+pub const PROP_GAIT: &str = "Gait:";
+pub const PROP_CADENCE: &str = "Cadence";
+pub const PROP_PHASE: &str = "Phase:";
+pub const PROP_LEAD: &str = "Lead:";
+pub const TOOLTIP_LEAD: &str = "Place the foot ahead of a moving body, in steps' worth of travel";
+pub const PROP_RELEASE: &str = "Release:";
+pub const TOOLTIP_RELEASE: &str = "How far past full length a planted foot may be dragged before it lets go and steps";
+pub const TOOLTIP_GAIT: &str = "Alternating keeps each phase group planted while another one is stepping";
+pub const TOOLTIP_CADENCE: &str = "Hand the turn to the next phase group this many times per second";
 pub const PROP_MOVEMENT_MODE: &str = "Movement Mode";
 pub const PROP_MOVEMENT_SPEED: &str = "Movement Speed";
 pub const PROP_PATH_TYPE: &str = "Path Type";
 pub const PROP_AMPLITUDE_X: &str = "Amplitude X";
 pub const PROP_AMPLITUDE_Y: &str = "Amplitude Y";
 pub const PROP_COLLISION_DAMP: &str = "Collision Damp";
+pub const PROP_CONSTRAINT_KIND: &str = "Kind";
+pub const PROP_PIN: &str = "Pin";
+pub const PROP_PINNED: &str = "Pinned";
+pub const PROP_BREAKABLE: &str = "Breakable";
+pub const TOOLTIP_BREAK_RATIO: &str = "Snaps once stretched past this multiple of its length";
+pub const TOOLTIP_COMPLIANCE: &str = "0 is rigid; higher values stretch like a spring";
 
 pub const SECTION_STEPPING: &str = "Stepping";
 pub const LABEL_JOINT: &str = "Joint";
@@ -71,6 +121,9 @@ pub const TOOL_CURSOR: &str = "Cursor";
 pub const TOOL_ADD_NODE: &str = "Add Node";
 pub const TOOL_ADD_EDGE: &str = "Add Edge";
 pub const TOOL_MOVE: &str = "Move";
+pub const TOOL_STAMP: &str = "Stamp Prefab";
+pub const TOOL_OBSTACLE: &str = "Draw Obstacle";
+pub const TOOL_DRAW_ARENA: &str = "Draw Arena";
 
 // =============================================================================
 // Playback Controls
@@ -78,6 +131,10 @@ pub const TOOL_MOVE: &str = "Move";
 
 pub const PLAYBACK_PLAY: &str = "Play";
 pub const PLAYBACK_PAUSE: &str = "Pause";
+pub const PLAYBACK_STOP: &str = "Stop & Reset";
+pub const PLAYBACK_STEP: &str = "Step Ticks";
+pub const PLAYBACK_SPEED: &str = "Speed";
+pub const LABEL_TICK_COUNTER: &str = "Tick";
 
 // =============================================================================
 // Instructions Overlay
@@ -86,4 +143,9 @@ pub const PLAYBACK_PAUSE: &str = "Pause";
 pub const HINT_FOLLOW_NODE: &str = "Follow Node: F";
 pub const HINT_TOGGLE_UI: &str = "Toggle UI: H";
 pub const HINT_PLAY: &str = "Play/Pause: Space";
+pub const HINT_STOP: &str = "Stop & Reset: Shift + Space";
+pub const HINT_MULTI_SELECT: &str = "Multi-Select: Shift/Ctrl + Click, Drag Box";
+pub const HINT_SELECT_CONNECTED: &str = "Select Connected: L";
+pub const HINT_CLIPBOARD: &str = "Copy/Paste/Duplicate: Ctrl + C / V / D";
+pub const HINT_UNDO: &str = "Undo/Redo: Ctrl + Z / Ctrl + Shift + Z";
 pub const HINT_VISIBILITY_TOGGLES: &str = "Visibility Toggles: Ctrl + 1-4";
@@ -13,7 +13,7 @@ use bevy_egui::EguiPrimaryContextPass;
 use icons::{UiIcons, EguiIconTextures};
 use state::*;
 use systems::{
-    apply_editor_actions, editor_ui_system, toggle_playback_control, toggle_ui_visibility, handle_visibility_shortcuts,
+    apply_editor_actions, capture_playback_snapshot, editor_ui_system, handle_history_shortcuts, toggle_playback_control, toggle_ui_visibility, handle_visibility_shortcuts,
 };
 
 /// Bevy plugin for editor UI (bevy_egui).
@@ -27,17 +27,27 @@ impl Plugin for UiPlugin {
         app.init_resource::<EditorToolState>();
         app.init_resource::<UiVisibility>();
         app.init_resource::<UiIcons>();
-        app.init_resource::<PlaybackState>();
         app.init_resource::<InputState>();
         app.init_resource::<EguiIconTextures>();
         app.init_resource::<ImportRequested>();
+        app.init_resource::<PlaybackSnapshot>();
+        app.init_resource::<PlaybackToolbarState>();
         app.init_resource::<PendingConstraintActions>();
+        app.init_resource::<PendingFieldActions>();
 
         app.add_systems(Startup, icons::load_icons);
+        // Capture before the fixed-step loop so the first simulated tick is never missed.
+        app.add_systems(PreUpdate, capture_playback_snapshot);
 
         app.add_systems(
             Update,
-            (toggle_ui_visibility, toggle_playback_control, handle_visibility_shortcuts, apply_editor_actions),
+            (
+                toggle_ui_visibility,
+                toggle_playback_control,
+                handle_visibility_shortcuts,
+                handle_history_shortcuts,
+                apply_editor_actions,
+            ),
         );
         // Run UI in egui pass so ctx.available_rect() is valid (after Context::run()).
         app.add_systems(EguiPrimaryContextPass, editor_ui_system);
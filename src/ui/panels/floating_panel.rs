@@ -3,8 +3,11 @@ use bevy_egui::egui;
 use bevy::window::PrimaryWindow;
 
 use crate::core::{
-    build_scene_data, deserialize_scene, export_to_file, import_from_file, Playground, Node as SimNode, DistanceConstraint, EXAMPLES,
+    build_scene_data, deserialize_scene, export_to_file, import_from_file, Playground, Node as SimNode, DistanceConstraint, Pin,
+    FieldFalloff, ForceField, ForceFieldKind, Obstacle, ObstacleShapeKind, BoundaryMode, PlaygroundShape, PrefabLibrary, SimulationRng, SimulationSettings, EXAMPLES,
 };
+use crate::editor::resources::EditorHistory;
+use crate::editor::tools::{capture_group_prefab, selection::Selection};
 use crate::core::components::LimbSet;
 use crate::ui::state::*;
 use crate::ui::theme::palette::*;
@@ -20,9 +23,19 @@ pub fn draw_floating_panel(
     display_settings: &mut DisplaySettings,
     import_requested: &mut ImportRequested,
     playground: &mut Playground,
+    sim_settings: &mut SimulationSettings,
+    rng: &mut SimulationRng,
+    prefabs: &mut PrefabLibrary,
+    selection: &Selection,
+    tool_state: &mut EditorToolState,
     node_query: &Query<(Entity, &mut SimNode)>,
     constraint_query: &Query<(Entity, &DistanceConstraint)>,
     limb_set_query: &mut Query<(Entity, &mut LimbSet)>,
+    pin_query: &Query<(Entity, &Pin)>,
+    field_query: &mut Query<(Entity, &mut ForceField)>,
+    obstacle_query: &Query<&Obstacle>,
+    pending_fields: &mut PendingFieldActions,
+    history: &mut EditorHistory,
     windows: &Query<&Window, With<PrimaryWindow>>,
 ) {
     let screen = ctx.available_rect();
@@ -92,7 +105,7 @@ pub fn draw_floating_panel(
                             }
                         }
                         if ui.button(BTN_EXPORT).clicked() {
-                            let scene = build_scene_data(node_query, constraint_query, limb_set_query);
+                            let scene = build_scene_data(node_query, constraint_query, limb_set_query, pin_query, field_query, obstacle_query, playground, rng.seed);
                             export_to_file(&scene);
                         }
 
@@ -123,6 +136,71 @@ pub fn draw_floating_panel(
                             }
                         }
 
+                        ui.add_space(PANEL_SECTION_SPACING);
+                        ui.label(egui::RichText::new(LABEL_PREFABS)
+                            .text_style(egui::TextStyle::Heading)
+                            .color(typography::heading_color()));
+
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(LABEL_PREFAB_NAME).text_style(egui::TextStyle::Small).color(typography::subinfo_color()));
+                            ui.text_edit_singleline(&mut panel_state.prefab_name);
+                        });
+                        let name = panel_state.prefab_name.trim();
+                        let can_save = selection.entity.is_some() && !name.is_empty();
+                        if ui.add_enabled(can_save, egui::Button::new(BTN_SAVE_PREFAB)).clicked() {
+                            if let Some(root) = selection.entity {
+                                prefabs.save(capture_group_prefab(name, root, node_query, constraint_query, limb_set_query, pin_query));
+                            }
+                        }
+
+                        if prefabs.prefabs.is_empty() {
+                            ui.colored_label(to_egui_color(TEXT_DISABLED), PLACEHOLDER_NO_PREFABS);
+                        }
+                        let mut armed = None;
+                        for (i, prefab) in prefabs.prefabs.iter().enumerate() {
+                            let is_armed = prefabs.armed == Some(i) && tool_state.active == EditorTool::Stamp;
+                            if ui.selectable_label(is_armed, &prefab.name).clicked() {
+                                armed = Some(if is_armed { None } else { Some(i) });
+                            }
+                        }
+                        if let Some(armed) = armed {
+                            prefabs.armed = armed;
+                            tool_state.active = if armed.is_some() { EditorTool::Stamp } else { EditorTool::Cursor };
+                        }
+                        if tool_state.active == EditorTool::Stamp {
+                            ui.label(egui::RichText::new(HINT_STAMP_PREFAB).text_style(egui::TextStyle::Small).color(typography::subinfo_color()));
+                        }
+
+                        ui.add_space(PANEL_SECTION_SPACING);
+                        draw_obstacle_section(ui, tool_state);
+
+                        ui.add_space(PANEL_SECTION_SPACING);
+                        draw_force_field_section(ui, panel_state, field_query, pending_fields, history);
+
+                        ui.add_space(PANEL_SECTION_SPACING);
+                        ui.separator();
+                        ui.add_space(PANEL_TITLE_SPACING);
+                        ui.label(egui::RichText::new(LABEL_SIMULATION)
+                            .text_style(egui::TextStyle::Heading)
+                            .color(typography::heading_color()));
+
+                        let mut tick_rate = sim_settings.tick_rate_hz;
+                        ui.vertical(|ui| {
+                            ui.label(egui::RichText::new(LABEL_TICK_RATE).text_style(egui::TextStyle::Small).color(typography::subinfo_color()));
+                            if ui.add(egui::Slider::new(&mut tick_rate, TICK_RATE_RANGE)).changed() {
+                                sim_settings.tick_rate_hz = tick_rate;
+                            }
+                        });
+
+                        let mut seed = rng.seed;
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(LABEL_SEED).text_style(egui::TextStyle::Small).color(typography::subinfo_color()));
+                            if ui.add(egui::DragValue::new(&mut seed)).changed() {
+                                rng.reseed(seed);
+                                history.mark_edited();
+                            }
+                        });
+
                         ui.add_space(PANEL_SECTION_SPACING);
                         ui.separator();
                         ui.add_space(PANEL_TITLE_SPACING);
@@ -130,10 +208,13 @@ pub fn draw_floating_panel(
                             .text_style(egui::TextStyle::Heading)
                             .color(typography::heading_color()));
 
+                        draw_playground_shape_picker(ui, playground, tool_state, history);
+                        draw_boundary_mode_picker(ui, playground, history);
+
                         let Ok(window) = windows.single() else { return };
                         let aspect = window.width() / window.height();
 
-                        let mut half_height = playground.half_size.y;
+                        let mut half_height = playground.half_size().y;
 
                         ui.vertical(|ui| {
                             ui.label(egui::RichText::new(LABEL_HALF_HEIGHT).text_style(egui::TextStyle::Small).color(typography::subinfo_color()));
@@ -142,7 +223,7 @@ pub fn draw_floating_panel(
                                 .changed()
                             {
                                 let half_width = half_height * aspect;
-                                playground.half_size = Vec2::new(half_width, half_height);
+                                playground.set_half_size(Vec2::new(half_width, half_height));
                             }
                         });
                     }
@@ -150,3 +231,164 @@ pub fn draw_floating_panel(
             });
         });
 }
+
+// =============================================================================
+// Private Methods
+// =============================================================================
+
+/// Arena shape selector, plus a button that arms the outline tool for custom polygons.
+fn draw_playground_shape_picker(ui: &mut egui::Ui, playground: &mut Playground, tool_state: &mut EditorToolState, history: &mut EditorHistory) {
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(LABEL_PLAYGROUND_SHAPE).text_style(egui::TextStyle::Small).color(typography::subinfo_color()));
+        egui::ComboBox::from_id_salt("playground_shape")
+            .selected_text(playground.shape().name())
+            .show_ui(ui, |ui| {
+                for shape in [PlaygroundShape::Rectangle, PlaygroundShape::Circle, PlaygroundShape::cave()] {
+                    let selected = std::mem::discriminant(playground.shape()) == std::mem::discriminant(&shape);
+                    if ui.selectable_label(selected, shape.name()).clicked() && !selected {
+                        playground.set_shape(shape);
+                        history.mark_edited();
+                    }
+                }
+            });
+    });
+
+    let drawing = tool_state.active == EditorTool::DrawArena;
+    if ui.selectable_label(drawing, BTN_DRAW_ARENA).clicked() {
+        tool_state.active = if drawing { EditorTool::Cursor } else { EditorTool::DrawArena };
+    }
+    if drawing {
+        ui.label(egui::RichText::new(HINT_DRAW_ARENA).text_style(egui::TextStyle::Small).color(typography::subinfo_color()));
+    }
+}
+
+/// Whether the playground edges bounce, wrap around or are left open.
+fn draw_boundary_mode_picker(ui: &mut egui::Ui, playground: &mut Playground, history: &mut EditorHistory) {
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(LABEL_BOUNDARY_MODE).text_style(egui::TextStyle::Small).color(typography::subinfo_color()));
+        egui::ComboBox::from_id_salt("playground_boundary_mode")
+            .selected_text(playground.boundary_mode.name())
+            .show_ui(ui, |ui| {
+                for mode in BoundaryMode::ALL {
+                    if ui.selectable_value(&mut playground.boundary_mode, mode, mode.name()).changed() {
+                        history.mark_edited();
+                    }
+                }
+            });
+    });
+}
+
+/// Shape picker that arms the obstacle drawing tool.
+fn draw_obstacle_section(ui: &mut egui::Ui, tool_state: &mut EditorToolState) {
+    ui.label(egui::RichText::new(LABEL_OBSTACLES)
+        .text_style(egui::TextStyle::Heading)
+        .color(typography::heading_color()));
+
+    ui.horizontal(|ui| {
+        for shape in ObstacleShapeKind::ALL {
+            let armed = tool_state.active == EditorTool::Obstacle && tool_state.obstacle_shape == shape;
+            if ui.selectable_label(armed, shape.name()).clicked() {
+                tool_state.obstacle_shape = shape;
+                tool_state.active = if armed { EditorTool::Cursor } else { EditorTool::Obstacle };
+            }
+        }
+    });
+    if tool_state.active == EditorTool::Obstacle {
+        let hint = if tool_state.obstacle_shape == ObstacleShapeKind::Polygon { HINT_DRAW_POLYGON } else { HINT_DRAW_OBSTACLE };
+        ui.label(egui::RichText::new(hint).text_style(egui::TextStyle::Small).color(typography::subinfo_color()));
+    }
+}
+
+/// Lists every force field with its settings, plus a picker for adding new ones.
+fn draw_force_field_section(
+    ui: &mut egui::Ui,
+    panel_state: &mut FloatingPanelState,
+    field_query: &mut Query<(Entity, &mut ForceField)>,
+    pending_fields: &mut PendingFieldActions,
+    history: &mut EditorHistory,
+) {
+    ui.label(egui::RichText::new(LABEL_FORCE_FIELDS)
+        .text_style(egui::TextStyle::Heading)
+        .color(typography::heading_color()));
+
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_salt("new_field_kind")
+            .selected_text(panel_state.new_field_kind.name())
+            .show_ui(ui, |ui| {
+                for kind in ForceFieldKind::ALL {
+                    ui.selectable_value(&mut panel_state.new_field_kind, kind, kind.name());
+                }
+            });
+        if ui.button(BTN_ADD_FIELD).clicked() {
+            pending_fields.spawns.push(panel_state.new_field_kind);
+        }
+    });
+
+    if field_query.is_empty() {
+        ui.colored_label(to_egui_color(TEXT_DISABLED), PLACEHOLDER_NO_FIELDS);
+    }
+    for (entity, mut field) in field_query.iter_mut() {
+        let mut edited = field.clone();
+        egui::CollapsingHeader::new(edited.kind.name())
+            .id_salt(entity)
+            .show(ui, |ui| {
+                draw_force_field_settings(ui, &mut edited);
+                if ui.button(BTN_REMOVE_FIELD).clicked() {
+                    pending_fields.deletes.push(entity);
+                }
+            });
+        if edited != *field {
+            *field = edited;
+            history.mark_edited();
+        }
+    }
+}
+
+fn draw_force_field_settings(ui: &mut egui::Ui, field: &mut ForceField) {
+    let small = |text: &str| egui::RichText::new(text).text_style(egui::TextStyle::Small).color(typography::subinfo_color());
+
+    ui.horizontal(|ui| {
+        ui.label(small(PROP_FIELD_KIND));
+        egui::ComboBox::from_id_salt(ui.id().with("kind"))
+            .selected_text(field.kind.name())
+            .show_ui(ui, |ui| {
+                for kind in ForceFieldKind::ALL {
+                    ui.selectable_value(&mut field.kind, kind, kind.name());
+                }
+            });
+    });
+    ui.label(small(PROP_FIELD_STRENGTH));
+    ui.add(egui::Slider::new(&mut field.strength, FIELD_STRENGTH_RANGE)).on_hover_text(TOOLTIP_FIELD_STRENGTH);
+    ui.label(small(PROP_FIELD_RADIUS));
+    ui.add(egui::Slider::new(&mut field.radius, FIELD_RADIUS_RANGE));
+    ui.horizontal(|ui| {
+        ui.label(small(PROP_FIELD_FALLOFF));
+        egui::ComboBox::from_id_salt(ui.id().with("falloff"))
+            .selected_text(field.falloff.name())
+            .show_ui(ui, |ui| {
+                for falloff in FieldFalloff::ALL {
+                    ui.selectable_value(&mut field.falloff, falloff, falloff.name());
+                }
+            });
+    });
+
+    match field.kind {
+        ForceFieldKind::Wind => {
+            ui.horizontal(|ui| {
+                ui.label(small(PROP_FIELD_DIRECTION));
+                ui.drag_angle(&mut field.direction);
+            });
+            ui.label(small(PROP_FIELD_GUST));
+            ui.add(egui::Slider::new(&mut field.gust, FIELD_GUST_RANGE));
+            ui.label(small(PROP_FIELD_FREQUENCY));
+            ui.add(egui::Slider::new(&mut field.frequency, FIELD_FREQUENCY_RANGE));
+        }
+        ForceFieldKind::Turbulence => {
+            ui.label(small(PROP_FIELD_NOISE_SCALE));
+            ui.add(egui::Slider::new(&mut field.noise_scale, FIELD_NOISE_SCALE_RANGE));
+            ui.label(small(PROP_FIELD_FREQUENCY));
+            ui.add(egui::Slider::new(&mut field.frequency, FIELD_FREQUENCY_RANGE));
+        }
+        ForceFieldKind::Radial | ForceFieldKind::Vortex => {}
+    }
+}
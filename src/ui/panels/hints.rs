@@ -24,9 +24,14 @@ pub fn draw_instruction_hints(ctx: &egui::Context) {
                 .selectable(false)
             };
 
+            ui.add(label(HINT_MULTI_SELECT));
+            ui.add(label(HINT_SELECT_CONNECTED));
+            ui.add(label(HINT_CLIPBOARD));
             ui.add(label(HINT_FOLLOW_NODE));
             ui.add(label(HINT_TOGGLE_UI));
             ui.add(label(HINT_PLAY));
+            ui.add(label(HINT_STOP));
+            ui.add(label(HINT_UNDO));
             ui.add(label(HINT_VISIBILITY_TOGGLES));
         });
 }
@@ -1,11 +1,15 @@
 use bevy::prelude::*;
 use bevy_egui::egui;
 
-use crate::core::constants::{MAX_CONSTRAINT_DISTANCE, MIN_CONSTRAINT_DISTANCE};
-use crate::core::components::LimbSet;
+use crate::core::constants::{
+    DEFAULT_BREAK_RATIO, DEFAULT_CADENCE, MAX_BREAK_RATIO, MAX_CADENCE, MAX_CONSTRAINT_COMPLIANCE, MAX_CONSTRAINT_DISTANCE,
+    MAX_NODE_MASS, MIN_BREAK_RATIO, MIN_CADENCE, MIN_CONSTRAINT_DISTANCE, MIN_NODE_MASS,
+};
+use crate::core::components::{GaitPattern, IkSolver, JointLimit, LimbSet};
 use crate::core::{
-    DistanceConstraint, Node as SimNode, NodeType, Playground, AnchorMovementMode, ProceduralPathType,
+    ConstraintKind, DistanceConstraint, Node as SimNode, Pin, NodeType, Playground, AnchorMovementMode, ProceduralPathType,
 };
+use crate::editor::resources::EditorHistory;
 use crate::editor::tools::selection::Selection;
 use crate::ui::state::*;
 use crate::ui::theme::palette::*;
@@ -23,7 +27,9 @@ pub fn draw_inspector_panel(
     node_query: &mut Query<(Entity, &mut SimNode)>,
     limb_set_query: &mut Query<(Entity, &mut LimbSet)>,
     constraint_query: &Query<(Entity, &DistanceConstraint)>,
+    pin_query: &Query<(Entity, &Pin)>,
     pending_actions: &mut PendingConstraintActions,
+    history: &mut EditorHistory,
 ) {
     let screen = ctx.available_rect();
     let right = screen.max.x;
@@ -37,9 +43,10 @@ pub fn draw_inspector_panel(
     let icon_bar_x = right - icon_bar_w;
     let inspector_x = icon_bar_x - inspector_w;
 
-    let mut constraint_updates: Vec<(Entity, f32)> = Vec::new();
+    let mut constraint_updates: Vec<(Entity, DistanceConstraint)> = Vec::new();
     let mut constraint_deletes: Vec<Entity> = Vec::new();
     let mut node_deletes: Vec<Entity> = Vec::new();
+    let mut pin_updates: Vec<(Entity, Option<Pin>)> = Vec::new();
 
     if inspector_state.open {
         egui::Area::new(egui::Id::new("inspector_panel"))
@@ -66,9 +73,12 @@ pub fn draw_inspector_panel(
                             node_query,
                             limb_set_query,
                             constraint_query,
+                            pin_query,
                             &mut constraint_updates,
                             &mut constraint_deletes,
                             &mut node_deletes,
+                            &mut pin_updates,
+                            history,
                         );
                     });
 
@@ -83,6 +93,7 @@ pub fn draw_inspector_panel(
     pending_actions.updates.extend(constraint_updates);
     pending_actions.deletes.extend(constraint_deletes);
     pending_actions.node_deletes.extend(node_deletes);
+    pending_actions.pin_updates.extend(pin_updates);
 
     // Inspector icon bar
     egui::Area::new(egui::Id::new("inspector_icon_bar"))
@@ -170,9 +181,12 @@ fn inspector_content_ui(
     node_query: &mut Query<(Entity, &mut SimNode)>,
     limb_set_query: &mut Query<(Entity, &mut LimbSet)>,
     constraint_query: &Query<(Entity, &DistanceConstraint)>,
-    constraint_updates: &mut Vec<(Entity, f32)>,
+    pin_query: &Query<(Entity, &Pin)>,
+    constraint_updates: &mut Vec<(Entity, DistanceConstraint)>,
     constraint_deletes: &mut Vec<Entity>,
     node_deletes: &mut Vec<Entity>,
+    pin_updates: &mut Vec<(Entity, Option<Pin>)>,
+    history: &mut EditorHistory,
 ) {
     let Some(selected_entity) = selection.entity else {
         ui.colored_label(to_egui_color(TEXT_SECONDARY), PLACEHOLDER_NO_SELECTION);
@@ -182,6 +196,7 @@ fn inspector_content_ui(
         ui.colored_label(to_egui_color(TEXT_SECONDARY), PLACEHOLDER_NO_SELECTION);
         return;
     };
+    let node_before = node.clone();
 
     match page {
         InspectorPage::Properties => {
@@ -214,6 +229,21 @@ fn inspector_content_ui(
                         });
                 });
                 ui.checkbox(&mut node.is_head, PROP_IS_HEAD);
+                ui.horizontal(|ui| {
+                    ui.label(PROP_MASS);
+                    let mut auto_mass = node.mass.is_none();
+                    if ui.checkbox(&mut auto_mass, PROP_MASS_AUTO).on_hover_text(TOOLTIP_MASS_AUTO).changed() {
+                        node.mass = if auto_mass { None } else { Some(node.effective_mass()) };
+                    }
+                    match node.mass.as_mut() {
+                        Some(mass) => {
+                            ui.add(egui::Slider::new(mass, MIN_NODE_MASS..=MAX_NODE_MASS).logarithmic(true));
+                        }
+                        None => {
+                            ui.colored_label(to_egui_color(TEXT_SECONDARY), format!("{:.2}", node.effective_mass()));
+                        }
+                    }
+                });
                 ui.add_space(PANEL_ITEM_SPACING);
                 ui.vertical(|ui| {
                     ui.label(PROP_ANGLE_MIN);
@@ -340,16 +370,56 @@ fn inspector_content_ui(
             });
             
             if let Ok((_e, mut limb_set)) = limb_set_query.get_mut(selected_entity) {
+                let limbs_before = limb_set.clone();
                 ui.collapsing(egui::RichText::new(SECTION_LIMB_IK)
                     .text_style(egui::TextStyle::Heading)
                     .color(typography::heading_color()), |ui| {
                     let limb_count = limb_set.limbs.len();
+
+                    ui.horizontal(|ui| {
+                        ui.label(PROP_GAIT).on_hover_text(TOOLTIP_GAIT);
+                        egui::ComboBox::from_id_salt("limb_gait")
+                            .selected_text(limb_set.gait.name())
+                            .show_ui(ui, |ui| {
+                                for gait in GaitPattern::ALL {
+                                    ui.selectable_value(&mut limb_set.gait, gait, gait.name());
+                                }
+                            });
+                    });
+                    if limb_set.gait != limbs_before.gait && limb_set.gait == GaitPattern::Alternating {
+                        limb_set.assign_alternating_phases();
+                    }
+
+                    let alternating = limb_set.gait == GaitPattern::Alternating;
+                    if alternating {
+                        ui.horizontal(|ui| {
+                            let mut forced = limb_set.cadence.is_some();
+                            if ui.checkbox(&mut forced, PROP_CADENCE).on_hover_text(TOOLTIP_CADENCE).changed() {
+                                limb_set.cadence = forced.then_some(DEFAULT_CADENCE);
+                            }
+                            if let Some(cadence) = limb_set.cadence.as_mut() {
+                                ui.add(egui::DragValue::new(cadence).speed(WIDGET_DRAG_SPEED_FINE).range(MIN_CADENCE..=MAX_CADENCE).suffix("/s"));
+                            }
+                        });
+                    }
+                    ui.add_space(PANEL_ITEM_SPACING);
+
                     for (i, limb) in limb_set.limbs.iter_mut().enumerate() {
                         ui.horizontal(|ui| {
                             ui.label(egui::RichText::new(format!("{} #{}  |  {} {}", LABEL_LIMB, i + 1, LABEL_JOINTS, limb.joints.len()))
                                 .text_style(egui::TextStyle::Heading)
                                 .color(typography::heading_color()));
                         });
+                        ui.horizontal(|ui| {
+                            ui.label(PROP_SOLVER).on_hover_text(TOOLTIP_SOLVER);
+                            egui::ComboBox::from_id_salt(("limb_solver", i))
+                                .selected_text(limb.solver.name())
+                                .show_ui(ui, |ui| {
+                                    for solver in IkSolver::ALL {
+                                        ui.selectable_value(&mut limb.solver, solver, solver.name());
+                                    }
+                                });
+                        });
                         ui.vertical(|ui| {
                             ui.label(PROP_MAX_REACH);
                             ui.add(egui::Slider::new(&mut limb.max_reach, LIMB_MAX_REACH_RANGE));
@@ -375,12 +445,46 @@ fn inspector_content_ui(
                             ui.label(PROP_HEIGHT);
                             ui.add(egui::DragValue::new(&mut limb.step_height).speed(WIDGET_DRAG_SPEED_FINE).range(LIMB_STEP_HEIGHT_RANGE));
                         });
+                        ui.horizontal(|ui| {
+                            ui.label(PROP_LEAD).on_hover_text(TOOLTIP_LEAD);
+                            ui.add(egui::DragValue::new(&mut limb.lead_factor).speed(WIDGET_DRAG_SPEED_FINE).range(LIMB_LEAD_RANGE));
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(PROP_RELEASE).on_hover_text(TOOLTIP_RELEASE);
+                            ui.add(egui::DragValue::new(&mut limb.release_ratio).speed(WIDGET_DRAG_SPEED_FINE).range(LIMB_RELEASE_RANGE));
+                        });
+                        if alternating {
+                            ui.horizontal(|ui| {
+                                ui.label(PROP_PHASE);
+                                ui.add(egui::DragValue::new(&mut limb.phase).range(LIMB_PHASE_RANGE));
+                            });
+                        }
                         
                         ui.add_space(PANEL_ITEM_SPACING);
                         ui.label(PROP_JOINT_FLIP);
                         for (j, flipped) in limb.flip_bend.iter_mut().enumerate() {
                              ui.checkbox(flipped, format!("{} {}", LABEL_JOINT, j + 1));
                         }
+
+                        ui.add_space(PANEL_ITEM_SPACING);
+                        ui.label(PROP_JOINT_LIMITS).on_hover_text(TOOLTIP_JOINT_LIMITS);
+                        for j in 0..limb.joints.len().saturating_sub(1) {
+                            let limit = limb.joint_limit(j);
+                            let (mut min_deg, mut max_deg) = (limit.min.to_degrees(), limit.max.to_degrees());
+                            let changed = ui.horizontal(|ui| {
+                                ui.label(format!("{} {}", LABEL_JOINT, j + 1));
+                                let min_changed = ui.add(egui::DragValue::new(&mut min_deg).range(LIMB_JOINT_LIMIT_RANGE).suffix("°")).changed();
+                                let max_changed = ui.add(egui::DragValue::new(&mut max_deg).range(LIMB_JOINT_LIMIT_RANGE).suffix("°")).changed();
+                                min_changed || max_changed
+                            }).inner;
+                            if changed {
+                                limb.joint_limits.resize(limb.joints.len(), JointLimit::default());
+                                limb.joint_limits[j] = JointLimit {
+                                    min: min_deg.min(max_deg).to_radians(),
+                                    max: max_deg.max(min_deg).to_radians(),
+                                };
+                            }
+                        }
                         
                         if i < limb_count - 1 {
                             ui.add_space(PANEL_TITLE_SPACING);
@@ -389,6 +493,9 @@ fn inspector_content_ui(
                         }
                     }
                 });
+                if *limb_set != limbs_before {
+                    history.mark_edited();
+                }
             }
 
             ui.collapsing(egui::RichText::new(SECTION_PHYSICS)
@@ -455,6 +562,37 @@ fn inspector_content_ui(
                 .color(typography::heading_color()), |ui| {
                 ui.add(egui::Slider::new(&mut node.radius, NODE_RADIUS_RANGE));
             });
+            ui.collapsing(egui::RichText::new(PROP_PIN)
+                .text_style(egui::TextStyle::Heading)
+                .color(typography::heading_color()), |ui| {
+                let current = pin_query.get(selected_entity).ok().map(|(_, pin)| pin.clone());
+                let mut edited = current.clone();
+
+                let mut pinned = edited.is_some();
+                if ui.checkbox(&mut pinned, PROP_PINNED).changed() {
+                    edited = pinned.then(|| Pin::new(node.position));
+                }
+                if let Some(pin) = edited.as_mut() {
+                    ui.horizontal(|ui| {
+                        ui.colored_label(to_egui_color(AXIS_X), "X");
+                        ui.add(egui::DragValue::new(&mut pin.point.x).speed(WIDGET_DRAG_SPEED));
+                        ui.add_space(PANEL_SECTION_SPACING);
+                        ui.colored_label(to_egui_color(AXIS_Y), "Y");
+                        ui.add(egui::DragValue::new(&mut pin.point.y).speed(WIDGET_DRAG_SPEED));
+                    });
+                    ui.add(
+                        egui::Slider::new(&mut pin.compliance, 0.0..=MAX_CONSTRAINT_COMPLIANCE)
+                            .logarithmic(true)
+                            .smallest_positive(COMPLIANCE_SMALLEST_POSITIVE)
+                            .text("Compliance"),
+                    )
+                    .on_hover_text(TOOLTIP_COMPLIANCE);
+                }
+
+                if edited != current {
+                    pin_updates.push((selected_entity, edited));
+                }
+            });
         }
         InspectorPage::Constraints => {
             let connected: Vec<(Entity, &DistanceConstraint)> = constraint_query
@@ -476,19 +614,56 @@ fn inspector_content_ui(
                                 }
                             });
                         });
-                        let mut len = constraint.rest_length;
-                        if ui
-                            .add(
-                                egui::Slider::new(&mut len, MIN_CONSTRAINT_DISTANCE..=MAX_CONSTRAINT_DISTANCE)
-                                    .text("Length"),
-                            )
-                            .changed()
-                        {
-                            constraint_updates.push((entity, len));
+                        let mut edited = constraint.clone();
+                        ui.add(
+                            egui::Slider::new(&mut edited.rest_length, MIN_CONSTRAINT_DISTANCE..=MAX_CONSTRAINT_DISTANCE)
+                                .text("Length"),
+                        );
+                        ui.horizontal(|ui| {
+                            ui.label(PROP_CONSTRAINT_KIND);
+                            egui::ComboBox::from_id_salt(("constraint_kind", entity))
+                                .selected_text(edited.kind.name())
+                                .show_ui(ui, |ui| {
+                                    for kind in ConstraintKind::ALL {
+                                        ui.selectable_value(&mut edited.kind, kind, kind.name());
+                                    }
+                                });
+                        });
+                        if edited.kind == ConstraintKind::Range {
+                            ui.add(
+                                egui::Slider::new(&mut edited.min_length, MIN_CONSTRAINT_DISTANCE..=MAX_CONSTRAINT_DISTANCE)
+                                    .text("Min"),
+                            );
+                            ui.add(
+                                egui::Slider::new(&mut edited.max_length, MIN_CONSTRAINT_DISTANCE..=MAX_CONSTRAINT_DISTANCE)
+                                    .text("Max"),
+                            );
+                        }
+                        ui.add(
+                            egui::Slider::new(&mut edited.compliance, 0.0..=MAX_CONSTRAINT_COMPLIANCE)
+                                .logarithmic(true)
+                                .smallest_positive(COMPLIANCE_SMALLEST_POSITIVE)
+                                .text("Compliance"),
+                        )
+                        .on_hover_text(TOOLTIP_COMPLIANCE);
+                        let mut breakable = edited.break_ratio.is_some();
+                        if ui.checkbox(&mut breakable, PROP_BREAKABLE).changed() {
+                            edited.break_ratio = breakable.then_some(DEFAULT_BREAK_RATIO);
+                        }
+                        if let Some(ratio) = edited.break_ratio.as_mut() {
+                            ui.add(egui::Slider::new(ratio, MIN_BREAK_RATIO..=MAX_BREAK_RATIO).text("Break At"))
+                                .on_hover_text(TOOLTIP_BREAK_RATIO);
+                        }
+                        if edited != *constraint {
+                            constraint_updates.push((entity, edited));
                         }
                     });
                 }
             }
         }
     }
+
+    if *node != node_before {
+        history.mark_edited();
+    }
 }
@@ -1,6 +1,8 @@
 use bevy_egui::egui;
 
+use crate::core::SimulationClock;
 use crate::ui::state::*;
+use crate::ui::messages::*;
 use crate::ui::theme::palette::*;
 use crate::ui::constants::*;
 use crate::ui::theme::*;
@@ -10,6 +12,9 @@ pub fn draw_playback_toolbar(
     ctx: &egui::Context,
     icons: &EguiIconTextures,
     playback: &mut PlaybackState,
+    snapshot: &mut PlaybackSnapshot,
+    toolbar_state: &mut PlaybackToolbarState,
+    clock: &SimulationClock,
 ) {
     let bottom_margin = BOTTOM_TOOLBAR_MARGIN;
     egui::Area::new(egui::Id::new("bottom_toolbar"))
@@ -37,7 +42,7 @@ pub fn draw_playback_toolbar(
                             egui::Button::new("▶").fill(to_egui_color(SURFACE)),
                         ),
                     };
-                    if play_btn.clicked() {
+                    if play_btn.on_hover_text(PLAYBACK_PLAY).clicked() {
                         playback.play();
                     }
                     let pause_btn = match icons.pause {
@@ -54,9 +59,63 @@ pub fn draw_playback_toolbar(
                             egui::Button::new("⏸").fill(to_egui_color(SURFACE)),
                         ),
                     };
-                    if pause_btn.clicked() {
+                    if pause_btn.on_hover_text(PLAYBACK_PAUSE).clicked() {
                         playback.pause();
                     }
+                    let stop_btn = match icons.stop {
+                        Some(tid) => ui.add_sized(
+                            egui::vec2(BOTTOM_TOOLBAR_BTN_SIZE, BOTTOM_TOOLBAR_BTN_SIZE),
+                            egui::Button::new(egui::Image::new(egui::load::SizedTexture::new(
+                                tid,
+                                egui::vec2(BOTTOM_TOOLBAR_ICON_SIZE, BOTTOM_TOOLBAR_ICON_SIZE),
+                            )))
+                            .fill(to_egui_color(SURFACE)),
+                        ),
+                        None => ui.add_sized(
+                            egui::vec2(BOTTOM_TOOLBAR_BTN_SIZE, BOTTOM_TOOLBAR_BTN_SIZE),
+                            egui::Button::new("⏹").fill(to_egui_color(SURFACE)),
+                        ),
+                    };
+                    if stop_btn.on_hover_text(PLAYBACK_STOP).clicked() {
+                        snapshot.stop(playback);
+                    }
+
+                    ui.separator();
+                    let step_btn = match icons.caret_right {
+                        Some(tid) => ui.add_sized(
+                            egui::vec2(BOTTOM_TOOLBAR_BTN_SIZE, BOTTOM_TOOLBAR_BTN_SIZE),
+                            egui::Button::new(egui::Image::new(egui::load::SizedTexture::new(
+                                tid,
+                                egui::vec2(BOTTOM_TOOLBAR_ICON_SIZE, BOTTOM_TOOLBAR_ICON_SIZE),
+                            )))
+                            .fill(to_egui_color(SURFACE)),
+                        ),
+                        None => ui.add_sized(
+                            egui::vec2(BOTTOM_TOOLBAR_BTN_SIZE, BOTTOM_TOOLBAR_BTN_SIZE),
+                            egui::Button::new("⏭").fill(to_egui_color(SURFACE)),
+                        ),
+                    };
+                    if step_btn.on_hover_text(PLAYBACK_STEP).clicked() {
+                        playback.step(toolbar_state.step_ticks);
+                    }
+                    ui.add(egui::DragValue::new(&mut toolbar_state.step_ticks).range(STEP_TICKS_RANGE))
+                        .on_hover_text(PLAYBACK_STEP);
+
+                    ui.separator();
+                    let mut time_scale = playback.time_scale;
+                    let speed = ui
+                        .add(egui::Slider::new(&mut time_scale, TIME_SCALE_RANGE).logarithmic(true).suffix("×"))
+                        .on_hover_text(PLAYBACK_SPEED);
+                    if speed.changed() {
+                        playback.time_scale = time_scale;
+                    }
+
+                    ui.separator();
+                    ui.label(
+                        egui::RichText::new(format!("{LABEL_TICK_COUNTER} {}", clock.tick))
+                            .text_style(egui::TextStyle::Small)
+                            .color(typography::subinfo_color()),
+                    );
                 });
             });
         });
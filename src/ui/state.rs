@@ -3,7 +3,9 @@
 use bevy::prelude::*;
 
 use super::messages::*;
-use crate::core::SceneData;
+use crate::core::{DistanceConstraint, ForceFieldKind, ObstacleShapeKind, Pin, SceneData};
+
+pub use crate::core::resources::{PlaybackMode, PlaybackState};
 
 /// Visibility toggles for editor elements.
 #[derive(Resource, Clone, Debug)]
@@ -30,6 +32,8 @@ pub struct FloatingPanelState {
     pub collapsed: bool,
     pub selected_example: Option<usize>,
     pub clear_on_import: bool,
+    pub prefab_name: String,
+    pub new_field_kind: ForceFieldKind,
 }
 
 impl Default for FloatingPanelState {
@@ -38,6 +42,8 @@ impl Default for FloatingPanelState {
             collapsed: false,
             selected_example: None,
             clear_on_import: true,
+            prefab_name: String::new(),
+            new_field_kind: ForceFieldKind::default(),
         }
     }
 }
@@ -79,12 +85,49 @@ impl Default for InspectorState {
 #[derive(Resource, Default)]
 pub struct ImportRequested(pub Option<SceneData>);
 
-/// Pending constraint rest_length updates and deletions. Applied in Update after egui.
+/// Scene captured when playback starts; Stop restores it and clears the capture.
+#[derive(Resource, Default)]
+pub struct PlaybackSnapshot {
+    pub scene: Option<SceneData>,
+    pub restore_requested: bool,
+}
+
+impl PlaybackSnapshot {
+    /// Pauses and, if a capture exists, queues it to be restored in Update.
+    pub fn stop(&mut self, playback: &mut PlaybackState) {
+        playback.pause();
+        self.restore_requested = self.scene.is_some();
+    }
+}
+
+/// Toolbar-only playback settings.
+#[derive(Resource, Clone, Debug)]
+pub struct PlaybackToolbarState {
+    /// Ticks advanced per press of the step button.
+    pub step_ticks: u32,
+}
+
+impl Default for PlaybackToolbarState {
+    fn default() -> Self {
+        Self { step_ticks: 1 }
+    }
+}
+
+/// Pending constraint edits and deletions. Applied in Update after egui.
 #[derive(Resource, Default)]
 pub struct PendingConstraintActions {
-    pub updates: Vec<(Entity, f32)>,
+    pub updates: Vec<(Entity, DistanceConstraint)>,
     pub deletes: Vec<Entity>,
     pub node_deletes: Vec<Entity>,
+    /// Pins to insert (`Some`) or remove (`None`) on a node.
+    pub pin_updates: Vec<(Entity, Option<Pin>)>,
+}
+
+/// Force fields to add at the view center or remove. Applied in Update after egui.
+#[derive(Resource, Default)]
+pub struct PendingFieldActions {
+    pub spawns: Vec<ForceFieldKind>,
+    pub deletes: Vec<Entity>,
 }
 
 
@@ -95,6 +138,12 @@ pub enum EditorTool {
     AddNode,
     AddEdge,
     Move,
+    /// Places the armed prefab from `PrefabLibrary` on click.
+    Stamp,
+    /// Draws `EditorToolState::obstacle_shape` obstacles.
+    Obstacle,
+    /// Clicks out a new polygon outline for the playground.
+    DrawArena,
 }
 
 impl EditorTool {
@@ -104,6 +153,9 @@ impl EditorTool {
             EditorTool::AddNode => TOOL_ADD_NODE,
             EditorTool::AddEdge => TOOL_ADD_EDGE,
             EditorTool::Move => TOOL_MOVE,
+            EditorTool::Stamp => TOOL_STAMP,
+            EditorTool::Obstacle => TOOL_OBSTACLE,
+            EditorTool::DrawArena => TOOL_DRAW_ARENA,
         }
     }
 }
@@ -111,40 +163,7 @@ impl EditorTool {
 #[derive(Resource, Clone, Debug, Default)]
 pub struct EditorToolState {
     pub active: EditorTool,
-}
-
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
-pub enum PlaybackMode {
-    Playing,
-    #[default]
-    Paused,
-}
-
-/// Shared resource that gates simulation systems.
-#[derive(Resource, Clone, Debug, Default, Reflect)]
-pub struct PlaybackState {
-    pub mode: PlaybackMode,
-}
-
-impl PlaybackState {
-    pub fn is_playing(&self) -> bool {
-        self.mode == PlaybackMode::Playing
-    }
-
-    pub fn play(&mut self) {
-        self.mode = PlaybackMode::Playing;
-    }
-
-    pub fn pause(&mut self) {
-        self.mode = PlaybackMode::Paused;
-    }
-
-    pub fn toggle(&mut self) {
-        self.mode = match self.mode {
-            PlaybackMode::Playing => PlaybackMode::Paused,
-            PlaybackMode::Paused => PlaybackMode::Playing,
-        };
-    }
+    pub obstacle_shape: ObstacleShapeKind,
 }
 
 #[derive(Resource, Clone, Debug)]
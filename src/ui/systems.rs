@@ -4,12 +4,13 @@ use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 use bevy_egui::EguiContexts;
 
-use crate::core::constants::{MAX_CONSTRAINT_DISTANCE, MIN_CONSTRAINT_DISTANCE};
 use crate::core::components::LimbSet;
 use crate::core::{
-    DistanceConstraint, Node as SimNode, Playground, spawn_scene_data, PendingFileOp,
+    DistanceConstraint, ForceField, Node as SimNode, Obstacle, Pin, Playground, PrefabLibrary, SimulationClock, SimulationRng, SimulationSettings, build_scene_data, spawn_scene_data,
+    PendingFileOp,
 };
 use crate::editor::components::{ConstraintPreview, ConstraintVisual, NodeVisual};
+use crate::editor::resources::{EditorHistory, HistoryStep};
 use crate::editor::tools::selection::Selection;
 use crate::ui::icons::{UiIcons, EguiIconTextures};
 use crate::ui::panels::*;
@@ -25,18 +26,36 @@ pub fn editor_ui_system(
         ResMut<InspectorState>,
     ),
     mut tool_state: ResMut<EditorToolState>,
-    mut playback: ResMut<PlaybackState>,
+    (mut playback, mut snapshot, mut toolbar_state, clock): (
+        ResMut<PlaybackState>,
+        ResMut<PlaybackSnapshot>,
+        ResMut<PlaybackToolbarState>,
+        Res<SimulationClock>,
+    ),
     ui_visibility: Res<UiVisibility>,
     mut input_state: ResMut<InputState>,
     icons: Res<UiIcons>,
     mut egui_icons: ResMut<EguiIconTextures>,
     mut import_requested: ResMut<ImportRequested>,
-    selection: Res<Selection>,
-    mut playground: ResMut<Playground>,
+    (selection, mut history): (Res<Selection>, ResMut<EditorHistory>),
+    (mut playground, mut sim_settings, mut rng, mut prefabs): (
+        ResMut<Playground>,
+        ResMut<SimulationSettings>,
+        ResMut<SimulationRng>,
+        ResMut<PrefabLibrary>,
+    ),
     mut node_query: Query<(Entity, &mut SimNode)>,
     mut limb_set_query: Query<(Entity, &mut LimbSet)>,
-    constraint_query: Query<(Entity, &DistanceConstraint)>,
-    mut pending_actions: ResMut<PendingConstraintActions>,
+    (constraint_query, pin_query, obstacle_query): (
+        Query<(Entity, &DistanceConstraint)>,
+        Query<(Entity, &Pin)>,
+        Query<&Obstacle>,
+    ),
+    (mut pending_actions, mut pending_fields, mut field_query): (
+        ResMut<PendingConstraintActions>,
+        ResMut<PendingFieldActions>,
+        Query<(Entity, &mut ForceField)>,
+    ),
     windows: Query<&Window, With<PrimaryWindow>>,
 ) {
     egui_icons.ensure_registered(&mut contexts, &icons);
@@ -56,9 +75,19 @@ pub fn editor_ui_system(
         &mut display_settings,
         &mut import_requested,
         &mut playground,
+        &mut sim_settings,
+        &mut rng,
+        &mut prefabs,
+        &selection,
+        &mut tool_state,
         &node_query,
         &constraint_query,
         &mut limb_set_query,
+        &pin_query,
+        &mut field_query,
+        &obstacle_query,
+        &mut pending_fields,
+        &mut history,
         &windows,
     );
 
@@ -78,13 +107,18 @@ pub fn editor_ui_system(
         &mut node_query,
         &mut limb_set_query,
         &constraint_query,
+        &pin_query,
         &mut pending_actions,
+        &mut history,
     );
 
     draw_playback_toolbar(
         ctx,
         &egui_icons,
         &mut playback,
+        &mut snapshot,
+        &mut toolbar_state,
+        &clock,
     );
 
     draw_instruction_hints(ctx);
@@ -99,22 +133,49 @@ pub fn toggle_ui_visibility(keyboard: Res<ButtonInput<KeyCode>>, mut ui_visibili
     }
 }
 
-/// Toggles playback state when Space is pressed, unless typing in UI.
+/// Toggles playback on Space and stops on Shift + Space, unless typing in UI.
 pub fn toggle_playback_control(
     mut contexts: EguiContexts,
     keyboard: Res<ButtonInput<KeyCode>>,
     mut playback: ResMut<PlaybackState>,
+    mut snapshot: ResMut<PlaybackSnapshot>,
 ) {
     if let Ok(ctx) = contexts.ctx_mut() {
         if ctx.wants_keyboard_input() {
             return;
         }
     }
-    if keyboard.just_pressed(KeyCode::Space) {
+    if !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+    if shift {
+        snapshot.stop(&mut playback);
+    } else {
         playback.toggle();
     }
 }
 
+/// Captures the authored scene the moment playback starts, so Stop can bring it back.
+pub fn capture_playback_snapshot(
+    playback: Res<PlaybackState>,
+    rng: Res<SimulationRng>,
+    playground: Res<Playground>,
+    mut snapshot: ResMut<PlaybackSnapshot>,
+    node_query: Query<(Entity, &mut SimNode)>,
+    constraint_query: Query<(Entity, &DistanceConstraint)>,
+    limb_set_query: Query<(Entity, &mut LimbSet)>,
+    pin_query: Query<(Entity, &Pin)>,
+    field_query: Query<(Entity, &mut ForceField)>,
+    obstacle_query: Query<&Obstacle>,
+) {
+    if !playback.is_changed() || !playback.is_playing() || snapshot.scene.is_some() {
+        return;
+    }
+    snapshot.scene = Some(build_scene_data(&node_query, &constraint_query, &limb_set_query, &pin_query, &field_query, &obstacle_query, &playground, rng.seed));
+}
+
 /// Shortcuts for visibility toggles: Ctrl + Numpad 1-4.
 pub fn handle_visibility_shortcuts(
     mut contexts: EguiContexts,
@@ -146,41 +207,98 @@ pub fn handle_visibility_shortcuts(
     }
 }
 
-/// Applies deferred import, constraint updates, and constraint deletes.
+/// Undo on Ctrl + Z, redo on Ctrl + Shift + Z, unless typing in UI.
+pub fn handle_history_shortcuts(
+    mut contexts: EguiContexts,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut history: ResMut<EditorHistory>,
+) {
+    if let Ok(ctx) = contexts.ctx_mut() {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+    }
+
+    let ctrl = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+    if !ctrl || !keyboard.just_pressed(KeyCode::KeyZ) {
+        return;
+    }
+
+    let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+    history.request(if shift { HistoryStep::Redo } else { HistoryStep::Undo });
+}
+
+/// Applies deferred import, stop/reset, undo/redo, constraint and force-field edits, and deletes.
 pub fn apply_editor_actions(
     mut commands: Commands,
     mut import_requested: ResMut<ImportRequested>,
     mut pending_file_op: ResMut<PendingFileOp>,
     mut pending_actions: ResMut<PendingConstraintActions>,
+    mut pending_fields: ResMut<PendingFieldActions>,
+    mut snapshot: ResMut<PlaybackSnapshot>,
+    mut history: ResMut<EditorHistory>,
     mut selection: ResMut<Selection>,
     panel_state: Res<FloatingPanelState>,
     node_query: Query<(Entity, &mut SimNode)>,
     mut constraint_query: Query<(Entity, &mut DistanceConstraint)>,
+    prop_query: Query<Entity, Or<(With<ForceField>, With<Obstacle>)>>,
+    camera_query: Query<&GlobalTransform, With<Camera2d>>,
     visual_entities: Query<Entity, Or<(With<NodeVisual>, With<ConstraintVisual>, With<ConstraintPreview>)>>,
 ) {
+    if snapshot.restore_requested {
+        snapshot.restore_requested = false;
+        if let Some(scene) = snapshot.scene.take() {
+            selection.deselect();
+            despawn_scene(&mut commands, &visual_entities, &constraint_query, &node_query, &prop_query);
+            spawn_scene_data(&mut commands, &scene);
+            // Simulated positions were never committed; recapture the restored scene.
+            history.baseline = None;
+        }
+    }
+
+    if let Some(scene) = history.restore.take() {
+        selection.deselect();
+        despawn_scene(&mut commands, &visual_entities, &constraint_query, &node_query, &prop_query);
+        spawn_scene_data(&mut commands, &scene);
+    }
+
     let scene_to_import = import_requested.0.take().or(pending_file_op.import_data.take());
     if let Some(scene) = scene_to_import {
+        // A freshly imported scene becomes the new authored state.
+        snapshot.scene = None;
         selection.deselect();
         if panel_state.clear_on_import {
-            for e in visual_entities.iter() {
-                commands.entity(e).despawn();
-            }
-            let constraint_list: Vec<Entity> = constraint_query.iter().map(|(e, _)| e).collect();
-            for e in constraint_list {
-                commands.entity(e).despawn();
-            }
-            let node_list: Vec<Entity> = node_query.iter().map(|(e, _)| e).collect();
-            for e in node_list {
-                commands.entity(e).despawn();
-            }
+            despawn_scene(&mut commands, &visual_entities, &constraint_query, &node_query, &prop_query);
         }
         spawn_scene_data(&mut commands, &scene);
+        history.mark_edited();
     }
-    for (entity, len) in pending_actions.updates.drain(..) {
+
+    let has_pending_edits = !pending_actions.updates.is_empty()
+        || !pending_actions.deletes.is_empty()
+        || !pending_actions.node_deletes.is_empty()
+        || !pending_actions.pin_updates.is_empty();
+    if has_pending_edits {
+        history.mark_edited();
+    }
+    for (entity, edited) in pending_actions.updates.drain(..) {
         if let Ok((_, mut c)) = constraint_query.get_mut(entity) {
-            c.rest_length = len.clamp(MIN_CONSTRAINT_DISTANCE, MAX_CONSTRAINT_DISTANCE);
+            c.rest_length = DistanceConstraint::clamp_rest_length(edited.rest_length);
+            c.compliance = DistanceConstraint::clamp_compliance(edited.compliance);
+            c.kind = edited.kind;
+            c.set_range(edited.min_length, edited.max_length);
+            c.break_ratio = edited.break_ratio.map(DistanceConstraint::clamp_break_ratio);
         }
     }
+    for (entity, pin) in pending_actions.pin_updates.drain(..) {
+        let Ok(mut node_commands) = commands.get_entity(entity) else {
+            continue;
+        };
+        match pin {
+            Some(pin) => node_commands.insert(Pin::new(pin.point).with_compliance(pin.compliance)),
+            None => node_commands.remove::<Pin>(),
+        };
+    }
     for entity in pending_actions.deletes.drain(..) {
         commands.entity(entity).despawn();
     }
@@ -193,4 +311,37 @@ pub fn apply_editor_actions(
         commands.entity(entity).despawn();
         selection.deselect();
     }
+
+    if !pending_fields.spawns.is_empty() || !pending_fields.deletes.is_empty() {
+        history.mark_edited();
+    }
+    let view_center = camera_query.single().map(|t| t.translation().truncate()).unwrap_or_default();
+    for kind in pending_fields.spawns.drain(..) {
+        commands.spawn((Name::new("Force Field"), ForceField::new(kind, view_center)));
+    }
+    for entity in pending_fields.deletes.drain(..) {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Despawns every node, constraint, force field, obstacle, and their visuals.
+fn despawn_scene(
+    commands: &mut Commands,
+    visual_entities: &Query<Entity, Or<(With<NodeVisual>, With<ConstraintVisual>, With<ConstraintPreview>)>>,
+    constraint_query: &Query<(Entity, &mut DistanceConstraint)>,
+    node_query: &Query<(Entity, &mut SimNode)>,
+    prop_query: &Query<Entity, Or<(With<ForceField>, With<Obstacle>)>>,
+) {
+    for e in visual_entities.iter() {
+        commands.entity(e).despawn();
+    }
+    for (e, _) in constraint_query.iter() {
+        commands.entity(e).despawn();
+    }
+    for (e, _) in node_query.iter() {
+        commands.entity(e).despawn();
+    }
+    for e in prop_query.iter() {
+        commands.entity(e).despawn();
+    }
 }